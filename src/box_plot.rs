@@ -0,0 +1,148 @@
+use crate::histogram::quantile;
+
+/// How far the whiskers of a box plot reach out from the box.
+///
+/// # Variants
+///
+/// * `Iqr` - to the furthest sample within the given number of interquartile ranges (IQR) from the box.
+///   Samples beyond the whiskers are outliers. `1.5` is the usual choice (Tukey's fences).
+/// * `MinMax` - to the lowest and highest sample, so there are no outliers.
+#[derive(Debug, Clone, Copy)]
+pub enum BoxWhiskers {
+    Iqr(f64),
+    MinMax,
+}
+
+/// Raw samples summarized into quartiles, whiskers and outliers for each bin, ready to be added to a [`crate::BarPlot`].
+///
+/// Each bin is given its own slice of samples, and the slices do not need to be of the same length.
+/// Quartiles are found by interpolating between the two closest samples.
+/// Samples that are `NaN` are left out.
+///
+/// # Example
+///
+/// ```
+/// use eb_bars::{BarPlot, BoxPlot, BoxWhiskers};
+///
+/// let monday = [12.0, 15.0, 14.0, 13.0, 41.0];
+/// let tuesday = [18.0, 16.0, 21.0, 19.0];
+///
+/// let box_plot = BoxPlot::new(&[&monday, &tuesday], BoxWhiskers::Iqr(1.5));
+///
+/// assert_eq!(box_plot.medians(), &[14.0, 18.5]);
+/// assert_eq!(box_plot.outliers()[0], &[41.0]);
+///
+/// let mut plot = BarPlot::new();
+/// plot.add_box_plot(&box_plot);
+///
+/// let svg: String = plot.to_svg(1600, 1000);
+/// ```
+#[derive(Debug)]
+pub struct BoxPlot {
+    lower_quartiles: Vec<f64>,
+    medians: Vec<f64>,
+    upper_quartiles: Vec<f64>,
+    lower_whiskers: Vec<f64>,
+    upper_whiskers: Vec<f64>,
+    outliers: Vec<Vec<f64>>,
+}
+
+impl BoxPlot {
+    /// Summarize the samples of each bin, with whiskers reaching as far as `whiskers` decides.
+    ///
+    /// # Panics
+    ///
+    /// * If there are no bins, or if any bin has no samples (not counting `NaN`).
+    /// * If the number of interquartile ranges for the whiskers is negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::{BoxPlot, BoxWhiskers};
+    ///
+    /// let samples = [1.0, 2.0, 3.0, 4.0, 5.0, 30.0];
+    ///
+    /// let box_plot = BoxPlot::new(&[&samples], BoxWhiskers::MinMax);
+    ///
+    /// // With min/max whiskers, every sample is within reach.
+    /// assert_eq!(box_plot.upper_whiskers(), &[30.0]);
+    /// assert!(box_plot.outliers()[0].is_empty());
+    /// ```
+    pub fn new(bins: &[&[f64]], whiskers: BoxWhiskers) -> Self {
+        assert!(!bins.is_empty(), "A box plot needs at least one bin.");
+        if let BoxWhiskers::Iqr(k) = whiskers {
+            assert!(k >= 0.0, "Whiskers can not reach a negative number of interquartile ranges, got {k}.");
+        }
+
+        let n_bins = bins.len();
+        let mut box_plot = Self {
+            lower_quartiles: Vec::with_capacity(n_bins),
+            medians: Vec::with_capacity(n_bins),
+            upper_quartiles: Vec::with_capacity(n_bins),
+            lower_whiskers: Vec::with_capacity(n_bins),
+            upper_whiskers: Vec::with_capacity(n_bins),
+            outliers: Vec::with_capacity(n_bins),
+        };
+
+        for (bin_index, samples) in bins.iter().enumerate() {
+            let mut sorted: Vec<f64> = samples.iter().copied().filter(|f| !f.is_nan()).collect();
+            assert!(!sorted.is_empty(), "Can not summarize bin {bin_index} without any samples.");
+            sorted.sort_by(f64::total_cmp);
+
+            let (lower_quartile, upper_quartile) = (quantile(&sorted, 0.25), quantile(&sorted, 0.75));
+            let (lower_fence, upper_fence) = match whiskers {
+                BoxWhiskers::Iqr(k) => {
+                    let iqr = upper_quartile - lower_quartile;
+                    (lower_quartile - (k * iqr), upper_quartile + (k * iqr))
+                }
+                BoxWhiskers::MinMax => (f64::NEG_INFINITY, f64::INFINITY),
+            };
+
+            // Whiskers end at the furthest sample inside the fences, the rest are outliers.
+            let inside = sorted.iter().copied().filter(|f| (lower_fence..=upper_fence).contains(f));
+            let (lower_whisker, upper_whisker) = inside.fold((lower_quartile, upper_quartile), |(low, high), f| {
+                (low.min(f), high.max(f))
+            });
+            let outliers = sorted.iter().copied().filter(|f| !(lower_fence..=upper_fence).contains(f)).collect();
+
+            box_plot.lower_quartiles.push(lower_quartile);
+            box_plot.medians.push(quantile(&sorted, 0.5));
+            box_plot.upper_quartiles.push(upper_quartile);
+            box_plot.lower_whiskers.push(lower_whisker);
+            box_plot.upper_whiskers.push(upper_whisker);
+            box_plot.outliers.push(outliers);
+        }
+
+        box_plot
+    }
+
+    /// The first quartile (25th percentile) of each bin, where the box starts.
+    pub fn lower_quartiles(&self) -> &[f64] {
+        &self.lower_quartiles
+    }
+
+    /// The median (50th percentile) of each bin.
+    pub fn medians(&self) -> &[f64] {
+        &self.medians
+    }
+
+    /// The third quartile (75th percentile) of each bin, where the box ends.
+    pub fn upper_quartiles(&self) -> &[f64] {
+        &self.upper_quartiles
+    }
+
+    /// Where the lower whisker of each bin ends.
+    pub fn lower_whiskers(&self) -> &[f64] {
+        &self.lower_whiskers
+    }
+
+    /// Where the upper whisker of each bin ends.
+    pub fn upper_whiskers(&self) -> &[f64] {
+        &self.upper_whiskers
+    }
+
+    /// The samples beyond the whiskers, for each bin.
+    pub fn outliers(&self) -> &[Vec<f64>] {
+        &self.outliers
+    }
+}
@@ -0,0 +1,396 @@
+use crate::{svg, BarMode, BarOrientation, BarPlot, Percentage};
+
+const DEFAULT_FIGURE_LEGEND_POSITION: (Percentage, Percentage) = (92.0, 5.0);
+
+/// Many plots laid out in a grid of rows and columns, rendered together as one svg.
+///
+/// Plots are placed row by row, from the top left to the bottom right, and each plot gets an equally sized panel.
+/// Every plot is rendered just like it would be on its own, only scaled down to the size of its panel.
+/// That includes its plot window, so [`BarPlot::set_plot_window_size`] decides how much room is left for the
+/// scale and bin markers within each panel.
+///
+/// Panels can share a scale, share bin markers and share a single legend, which keeps them aligned and comparable.
+///
+/// # Example
+///
+/// ```
+/// use eb_bars::{BarPlot, Figure};
+///
+/// let north = [5., 16., 17., 8., 3.];
+/// let south = [7., 6., 7., 16., 9.];
+///
+/// let mut figure = Figure::new(1, 2);
+///
+/// for values in [&north, &south] {
+///     let mut plot = BarPlot::new();
+///     plot.add_values(values);
+///     plot.set_plot_window_size(90.0, 80.0, 85.0, 40.0);
+///     figure.add_plot(plot);
+/// }
+///
+/// figure.set_shared_scale();
+///
+/// let svg: String = figure.to_svg(1600, 600);
+/// ```
+#[derive(Debug)]
+pub struct Figure<'a> {
+    rows: usize,
+    columns: usize,
+    plots: Vec<BarPlot<'a>>,
+    shared_scale: bool,
+    scale_range: Option<(f64, f64, f64)>,
+    markers: Option<&'a [&'a str]>,
+    legend: Option<&'a [&'a str]>,
+    legend_position: Option<(Percentage, Percentage)>,
+    background: Option<&'a str>,
+}
+
+impl <'a>Figure<'a> {
+    /// Instantiate a new figure with room for `rows` times `columns` plots.
+    ///
+    /// # Panics
+    ///
+    /// If there are no rows or no columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::Figure;
+    ///
+    /// // Room for six plots, three on each row.
+    /// let mut figure = Figure::new(2, 3);
+    /// ```
+    pub fn new(rows: usize, columns: usize) -> Self {
+        assert!(rows > 0 && columns > 0, "A figure needs at least one row and one column.");
+
+        Self {
+            rows,
+            columns,
+            plots: Vec::with_capacity(rows * columns),
+            shared_scale: false,
+            scale_range: None,
+            markers: None,
+            legend: None,
+            legend_position: None,
+            background: None,
+        }
+    }
+
+    /// Add a plot to the next free panel.
+    ///
+    /// Panels are filled row by row, so the first plot goes to the top left and the last to the bottom right.
+    /// The figure takes ownership of the plot, so make all the tweaks to it before it is added.
+    ///
+    /// # Panics
+    ///
+    /// If all panels are already taken.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::{BarPlot, Figure};
+    ///
+    /// let mut figure = Figure::new(1, 2);
+    ///
+    /// let mut plot = BarPlot::new();
+    /// plot.add_values(&[1., 2., 3.]);
+    /// figure.add_plot(plot);
+    ///
+    /// let mut plot = BarPlot::new();
+    /// plot.add_values(&[3., 2., 1.]);
+    /// figure.add_plot(plot);
+    ///
+    /// let svg: String = figure.to_svg(1600, 1000);
+    /// ```
+    pub fn add_plot(&mut self, plot: BarPlot<'a>) {
+        let n_panels = self.rows * self.columns;
+        assert!(self.plots.len() < n_panels, "All {n_panels} panels of the figure are already taken.");
+        self.plots.push(plot);
+    }
+
+    /// Let all plots share one scale that covers the values of every plot.
+    ///
+    /// The scale is picked the same way as with [`BarPlot::set_scale_range_auto`], except that it fits all plots.
+    /// This overrides any scale set on the plots themselves.
+    ///
+    /// Note: Plots with bars stacked by percentage keep their own scale from 0 to 100%.
+    ///
+    /// # Important
+    ///
+    /// Timelines from [`BarPlot::add_timeline`] share a time scale instead, picked the same way as on their own.
+    /// A time scale can not be shared with plots of regular values, mixing them will panic in [`Figure::to_svg`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::{BarPlot, Figure};
+    ///
+    /// let mut figure = Figure::new(2, 1);
+    ///
+    /// let (small, large) = ([1., 2., 3.], [10., 20., 30.]);
+    ///
+    /// for values in [&small, &large] {
+    ///     let mut plot = BarPlot::new();
+    ///     plot.add_values(values);
+    ///     figure.add_plot(plot);
+    /// }
+    ///
+    /// // Both plots are scaled from 0 to 30, so the bars in the top panel look small.
+    /// figure.set_shared_scale();
+    ///
+    /// let svg: String = figure.to_svg(1600, 1000);
+    /// ```
+    pub fn set_shared_scale(&mut self) {
+        self.shared_scale = true;
+        self.scale_range = None;
+    }
+
+    /// Let all plots share the given scale.
+    ///
+    /// Works like [`BarPlot::set_scale_range`], only for every plot in the figure.
+    /// This overrides any scale set on the plots themselves.
+    ///
+    /// Note: Plots with bars stacked by percentage keep their own scale from 0 to 100%.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::{BarPlot, Figure};
+    ///
+    /// let mut figure = Figure::new(2, 1);
+    ///
+    /// let (small, large) = ([1., 2., 3.], [10., 20., 30.]);
+    ///
+    /// for values in [&small, &large] {
+    ///     let mut plot = BarPlot::new();
+    ///     plot.add_values(values);
+    ///     figure.add_plot(plot);
+    /// }
+    ///
+    /// figure.set_shared_scale_range(0, 40, 10);
+    ///
+    /// let svg: String = figure.to_svg(1600, 1000);
+    /// ```
+    pub fn set_shared_scale_range(&mut self, min: i64, max: i64, step: u64) {
+        self.shared_scale = true;
+        self.scale_range = Some((min as f64, max as f64, step as f64));
+    }
+
+    /// Let all plots share the same bin markers, shown only along the outer edge of the figure.
+    ///
+    /// With vertical bars the markers are shown below the plots on the bottom row, and with horizontal bars
+    /// they are shown to the left of the plots in the first column. Radial plots show them in every panel.
+    /// The other plots still get their ticks and grid lines, only without text.
+    ///
+    /// This overrides any bin markers set on the plots themselves.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::{BarPlot, Figure};
+    ///
+    /// let mut figure = Figure::new(2, 1);
+    ///
+    /// let (up, down) = ([1., 2., 3.], [3., 2., 1.]);
+    ///
+    /// for values in [&up, &down] {
+    ///     let mut plot = BarPlot::new();
+    ///     plot.add_values(values);
+    ///     plot.set_plot_window_size(90.0, 80.0, 80.0, 30.0);
+    ///     figure.add_plot(plot);
+    /// }
+    ///
+    /// // Only the bottom plot shows the markers.
+    /// figure.set_shared_bin_markers(&["Jan", "Feb", "Mar"]);
+    ///
+    /// let svg: String = figure.to_svg(1600, 1000);
+    /// ```
+    pub fn set_shared_bin_markers(&mut self, markers: &'a [&'a str]) {
+        self.markers = Some(markers);
+    }
+
+    /// Show a single legend for the whole figure, instead of one in each plot.
+    ///
+    /// The legend takes its colors, text color and font-size from the first plot in the figure,
+    /// so color the categories the same way in all plots.
+    /// Any legend set on the plots themselves is left out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::{BarPlot, Figure};
+    ///
+    /// let mut figure = Figure::new(1, 2);
+    ///
+    /// let spring = ([5., 16., 17.], [7., 6., 7.]);
+    /// let autumn = ([8., 3., 4.], [16., 9., 12.]);
+    ///
+    /// for (apples, oranges) in [&spring, &autumn] {
+    ///     let mut plot = BarPlot::new();
+    ///     plot.add_values(apples);
+    ///     plot.add_values(oranges);
+    ///     plot.add_bar_colors_by_category("Red");
+    ///     plot.add_bar_colors_by_category("Orange");
+    ///     figure.add_plot(plot);
+    /// }
+    ///
+    /// figure.set_legend(&["Apples", "Oranges"]);
+    ///
+    /// let svg: String = figure.to_svg(1600, 1000);
+    /// ```
+    pub fn set_legend(&mut self, categories: &'a [&'a str]) {
+        self.legend = Some(categories);
+    }
+
+    /// Set legend position in percentage of the whole figure.
+    ///
+    /// By default, the legend is placed in the top right corner of the figure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::{BarPlot, Figure};
+    ///
+    /// let mut figure = Figure::new(1, 1);
+    ///
+    /// let mut plot = BarPlot::new();
+    /// plot.add_values(&[1., 2., 3.]);
+    /// figure.add_plot(plot);
+    ///
+    /// figure.set_legend(&["Values"]);
+    /// figure.set_legend_position(10.0, 10.0);
+    ///
+    /// let svg: String = figure.to_svg(1600, 1000);
+    /// ```
+    pub fn set_legend_position(&mut self, x: Percentage, y: Percentage) {
+        self.legend_position = Some((x, y));
+    }
+
+    /// Set a fill color as background for the whole figure.
+    ///
+    /// Plots with their own background color are drawn on top of it, each covering its own panel.
+    ///
+    /// # Accepted color conventions.
+    ///
+    /// * As its name such as "Red".
+    /// * As an RGB value such as "rgb(29, 28, 27)".
+    /// * As a HEX value such as "#1111FA".
+    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::{BarPlot, Figure};
+    ///
+    /// let mut figure = Figure::new(1, 1);
+    ///
+    /// let mut plot = BarPlot::new();
+    /// plot.add_values(&[1., 2., 3.]);
+    /// figure.add_plot(plot);
+    ///
+    /// figure.set_background_color("Black");
+    ///
+    /// let svg: String = figure.to_svg(1600, 1000);
+    /// ```
+    pub fn set_background_color(&mut self, color: &'a str) {
+        self.background = Some(color);
+    }
+
+    /// Generate the final svg image of all plots in the figure.
+    ///
+    /// Each panel is `width / columns` wide and `height / rows` high.
+    ///
+    /// # Panics
+    ///
+    /// * If no plots are added.
+    /// * If any of the plots would panic on [`BarPlot::to_svg`].
+    /// * If timelines and plots of regular values are sharing one scale, see [`Figure::set_shared_scale`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::{BarPlot, Figure};
+    ///
+    /// let mut figure = Figure::new(1, 1);
+    ///
+    /// let mut plot = BarPlot::new();
+    /// plot.add_values(&[1., 2., 3.]);
+    /// figure.add_plot(plot);
+    ///
+    /// let svg: String = figure.to_svg(1600, 1000);
+    /// ```
+    pub fn to_svg(&mut self, width: u32, height: u32) -> String {
+        assert!(!self.plots.is_empty(), "Can not generate figure without any plots..");
+
+        let panel_size = (width / self.columns as u32, height / self.rows as u32);
+
+        // Percentage stacks bring their own scale, so they are left out when looking for a shared one.
+        let scale_range = match self.scale_range {
+            Some(scale_range) => Some(scale_range),
+            None if self.shared_scale => {
+                let plots: Vec<&BarPlot> = self.plots
+                    .iter()
+                    .filter(|plot| !matches!(plot.layout.bar_mode, BarMode::StackedPercent))
+                    .collect();
+                let time_axis = plots.iter().any(|plot| plot.layout.time_axis);
+                assert!(
+                    plots.iter().all(|plot| plot.layout.time_axis == time_axis),
+                    "Can not share one scale between timelines and plots of regular values.",
+                );
+
+                let extent = plots
+                    .into_iter()
+                    .map(svg::value_extent)
+                    .reduce(|(min_a, max_a), (min_b, max_b)| (min_a.min(min_b), max_a.max(max_b)));
+                match time_axis {
+                    true => extent.map(svg::nice_time_scale),
+                    false => extent.map(svg::nice_scale),
+                }
+            }
+            None => None,
+        };
+
+        let n_plots = self.plots.len();
+        let mut panels = Vec::with_capacity(n_plots);
+        for (i, plot) in self.plots.iter_mut().enumerate() {
+            let (row, column) = (i / self.columns, i % self.columns);
+
+            // Percentage stacks keep their own scale, just as they are left out when looking for a shared one.
+            let own_scale = matches!(plot.layout.bar_mode, BarMode::StackedPercent);
+            if let (Some(scale_range), false) = (scale_range, own_scale) {
+                plot.layout.scale_range = Some(scale_range);
+                plot.layout.scale_range_auto = false;
+            }
+
+            if let Some(markers) = self.markers {
+                // Only the panels along the outer edge (where there is no panel beyond them) show text.
+                let outer_edge = match plot.layout.orientation {
+                    BarOrientation::Vertical => i + self.columns >= n_plots,
+                    BarOrientation::Horizontal => column == 0,
+                    BarOrientation::Radial => true,
+                };
+                plot.markers = match outer_edge {
+                    true => Some(markers.to_vec()),
+                    false => Some(vec![""; markers.len()]),
+                };
+            }
+
+            if self.legend.is_some() {
+                plot.legend.categories = None;
+            }
+
+            plot.size = panel_size;
+            plot.assert_valid();
+
+            let x = (column as u32 * panel_size.0) as f64;
+            let y = (row as u32 * panel_size.1) as f64;
+            panels.push(svg::render_panel(plot, x, y));
+        }
+
+        let legend = self.legend.map(|categories| {
+            (categories, self.legend_position.unwrap_or(DEFAULT_FIGURE_LEGEND_POSITION))
+        });
+
+        svg::render_figure((width, height), &panels, self.background, legend, &self.plots[0])
+    }
+}
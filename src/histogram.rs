@@ -0,0 +1,498 @@
+use crate::svg::format_number;
+
+/// Strategies for splitting raw samples into bins.
+///
+/// All strategies except [`HistogramBins::Edges`] span from the lowest to the highest sample.
+///
+/// # Variants
+///
+/// * `Count` - a fixed number of bins of equal width.
+/// * `Width` - bins of a fixed width, aligned to whole multiples of the width (e.g. 0, 5, 10.. for a width of 5).
+/// * `Edges` - explicit bin edges, must be at least two and strictly increasing.
+///   Samples outside of the first and last edge are left out.
+/// * `Sturges` - bin count from Sturges' rule, `log2(n) + 1`. Works well for normal distributions.
+/// * `Scott` - bin width from Scott's rule, `3.49 * standard deviation / n^(1/3)`.
+/// * `FreedmanDiaconis` - bin width from the Freedman–Diaconis rule, `2 * IQR / n^(1/3)`. Robust against outliers.
+///
+/// If `Scott` or `FreedmanDiaconis` end up with more than 1000 bins (e.g. one sample far away from the rest),
+/// the bin count from `Sturges` is used instead.
+#[derive(Debug, Clone)]
+pub enum HistogramBins {
+    Count(usize),
+    Width(f64),
+    Edges(Vec<f64>),
+    Sturges,
+    Scott,
+    FreedmanDiaconis,
+}
+
+// Most bins a rule of thumb may give before falling back to Sturges' rule.
+const MAX_RULE_BINS: usize = 1000;
+
+// Number of points along the x-axis where the kernel density estimate is evaluated.
+const KDE_RESOLUTION: usize = 200;
+
+#[derive(Debug, Clone, Copy)]
+enum Normalize {
+    Fraction, // Bars sum up to 1.
+    Density, // Bar areas sum up to 1.
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Cumulative {
+    Forward, // Each bin holds itself and all bins to the left of it.
+    Reversed, // Each bin holds itself and all bins to the right of it.
+}
+
+/// Raw samples sorted into bins, ready to be added to a [`crate::BarPlot`].
+///
+/// Every bin includes its left edge and excludes its right edge, except the last bin which includes both.
+/// E.g. with edges `[0, 1, 2]`, the sample `1.0` goes into the second bin and so does `2.0`.
+/// Samples that are `NaN` or infinite are left out.
+///
+/// # Example
+///
+/// ```
+/// use eb_bars::{BarPlot, Histogram, HistogramBins};
+///
+/// let samples = [1.2, 3.4, 3.9, 4.1, 7.5, 9.9, 5.0, 4.4];
+/// let histogram = Histogram::new(&samples, HistogramBins::Edges(vec![0.0, 2.5, 5.0, 7.5, 10.0]));
+///
+/// assert_eq!(histogram.counts(), &[1.0, 4.0, 1.0, 2.0]);
+///
+/// let mut plot = BarPlot::new();
+/// plot.add_histogram(&histogram);
+///
+/// let svg: String = plot.to_svg(1600, 1000);
+/// ```
+#[derive(Debug)]
+pub struct Histogram<'a> {
+    samples: Vec<f64>,
+    edges: Vec<f64>,
+    counts: Vec<f64>,
+    values: Vec<f64>,
+    markers: Vec<String>,
+    cumulative: Option<Cumulative>,
+    normalize: Option<Normalize>,
+    kde_color: Option<&'a str>,
+    kde_bandwidth: Option<f64>,
+}
+
+impl <'a>Histogram<'a> {
+    /// Sort raw samples into bins using the given strategy.
+    ///
+    /// # Panics
+    ///
+    /// * If there are no samples (not counting `NaN` and infinite samples).
+    /// * If the bin count or bin width is zero, or if explicit edges are fewer than two or not increasing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::{Histogram, HistogramBins};
+    ///
+    /// let samples = [2.0, 3.0, 3.5, 8.0];
+    ///
+    /// let histogram = Histogram::new(&samples, HistogramBins::Width(5.0));
+    ///
+    /// assert_eq!(histogram.edges(), &[0.0, 5.0, 10.0]);
+    /// assert_eq!(histogram.counts(), &[3.0, 1.0]);
+    /// ```
+    pub fn new(samples: &[f64], bins: HistogramBins) -> Self {
+        let mut sorted: Vec<f64> = samples.iter().copied().filter(|f| f.is_finite()).collect();
+        assert!(!sorted.is_empty(), "Can not create a histogram without any samples..");
+        sorted.sort_by(f64::total_cmp);
+
+        let edges = match bins {
+            HistogramBins::Count(count) => even_edges(&sorted, count),
+            HistogramBins::Width(width) => aligned_edges(&sorted, width),
+            HistogramBins::Edges(edges) => {
+                assert!(edges.len() >= 2, "A histogram needs at least two edges, got {}.", edges.len());
+                assert!(
+                    edges.windows(2).all(|pair| pair[0] < pair[1]),
+                    "Histogram edges must be strictly increasing.",
+                );
+                edges
+            }
+            HistogramBins::Sturges => even_edges(&sorted, sturges_count(&sorted)),
+            HistogramBins::Scott => {
+                let n = sorted.len() as f64;
+                let width = 3.49 * std_dev(&sorted) / n.cbrt();
+                even_edges(&sorted, count_from_width(&sorted, width))
+            }
+            HistogramBins::FreedmanDiaconis => {
+                let n = sorted.len() as f64;
+                let iqr = quantile(&sorted, 0.75) - quantile(&sorted, 0.25);
+                let width = 2.0 * iqr / n.cbrt();
+                even_edges(&sorted, count_from_width(&sorted, width))
+            }
+        };
+
+        let mut counts = vec![0.0; edges.len() - 1];
+        let last = counts.len() - 1;
+        for f in sorted.iter().copied() {
+            if f < edges[0] || f > edges[last + 1] {
+                continue;
+            }
+            // Index of the first edge greater than the sample, minus one, is the bin it belongs to.
+            let bin_index = edges.partition_point(|edge| *edge <= f).saturating_sub(1);
+            counts[bin_index.min(last)] += 1.0;
+        }
+
+        let markers = edges.iter().copied().map(format_number).collect();
+        let values = counts.clone();
+
+        Self {
+            samples: sorted,
+            edges,
+            counts,
+            values,
+            markers,
+            cumulative: None,
+            normalize: None,
+            kde_color: None,
+            kde_bandwidth: None,
+        }
+    }
+
+    /// Turn the histogram into a running total, from left to right.
+    ///
+    /// Each bar will hold the number of samples in its own bin plus all bins to the left of it.
+    /// The last bar will therefore hold the number of all binned samples.
+    ///
+    /// Note: check out [`Histogram::set_cumulative_reversed`] for the opposite direction
+    /// and [`Histogram::set_fraction`] for a running fraction instead of a running total.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::{Histogram, HistogramBins};
+    ///
+    /// let samples = [1.0, 2.0, 2.5, 3.0];
+    /// let mut histogram = Histogram::new(&samples, HistogramBins::Edges(vec![0.0, 1.5, 3.0]));
+    ///
+    /// histogram.set_cumulative();
+    ///
+    /// assert_eq!(histogram.values(), &[1.0, 4.0]);
+    /// ```
+    pub fn set_cumulative(&mut self) {
+        self.cumulative = Some(Cumulative::Forward);
+        self.update_values();
+    }
+
+    /// Turn the histogram into a running total, from right to left.
+    ///
+    /// Each bar will hold the number of samples in its own bin plus all bins to the right of it,
+    /// e.g. how many samples are greater than or equal to the left edge of the bin.
+    /// This is also known as a survival function, handy for questions like _how many requests took longer than X_.
+    ///
+    /// Note: check out [`Histogram::set_cumulative`] for the opposite direction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::{Histogram, HistogramBins};
+    ///
+    /// let samples = [1.0, 2.0, 2.5, 3.0];
+    /// let mut histogram = Histogram::new(&samples, HistogramBins::Edges(vec![0.0, 1.5, 3.0]));
+    ///
+    /// histogram.set_cumulative_reversed();
+    ///
+    /// assert_eq!(histogram.values(), &[4.0, 3.0]);
+    /// ```
+    pub fn set_cumulative_reversed(&mut self) {
+        self.cumulative = Some(Cumulative::Reversed);
+        self.update_values();
+    }
+
+    /// Divide every bar by the number of binned samples.
+    ///
+    /// Bars will hold the fraction of samples in each bin instead of the count, so that all bars sum up to 1.
+    /// This is also known as probability mass.
+    /// Together with [`Histogram::set_cumulative`] (or [`Histogram::set_cumulative_reversed`])
+    /// this gives a running fraction that ends up at 1.
+    ///
+    /// Since the values are fractions, use [`crate::BarPlot::set_scale_range_auto`] for the scale.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::{BarPlot, Histogram, HistogramBins};
+    ///
+    /// let samples = [1.0, 2.0, 2.5, 3.0];
+    /// let mut histogram = Histogram::new(&samples, HistogramBins::Edges(vec![0.0, 1.5, 3.0]));
+    ///
+    /// histogram.set_cumulative();
+    /// histogram.set_fraction();
+    ///
+    /// assert_eq!(histogram.values(), &[0.25, 1.0]);
+    ///
+    /// let mut plot = BarPlot::new();
+    /// plot.add_histogram(&histogram);
+    /// plot.set_scale_range_auto();
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_fraction(&mut self) {
+        self.normalize = Some(Normalize::Fraction);
+        self.update_values();
+    }
+
+    /// Divide every bar by the number of binned samples times the width of its bin.
+    ///
+    /// Bars will hold the probability density of each bin instead of the count,
+    /// so that the total area of all bars (height times bin width) sums up to 1.
+    /// Unlike [`Histogram::set_fraction`], this keeps the shape of the distribution
+    /// when bins are of unequal width. It also makes histograms of different sample sizes comparable.
+    ///
+    /// Calling this overrides [`Histogram::set_fraction`] and vice versa.
+    ///
+    /// # Important
+    ///
+    /// A density can not be cumulative, combining this with [`Histogram::set_cumulative`]
+    /// (or [`Histogram::set_cumulative_reversed`]) will panic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::{Histogram, HistogramBins};
+    ///
+    /// let samples = [1.0, 2.0, 2.5, 3.0];
+    /// let mut histogram = Histogram::new(&samples, HistogramBins::Edges(vec![0.0, 2.0, 3.0]));
+    ///
+    /// histogram.set_density();
+    ///
+    /// // One out of four samples spread over a width of 2, three out of four over a width of 1.
+    /// assert_eq!(histogram.values(), &[0.125, 0.75]);
+    /// ```
+    pub fn set_density(&mut self) {
+        self.normalize = Some(Normalize::Density);
+        self.update_values();
+    }
+
+    /// Draw a kernel density estimate as a smooth curve over the bars.
+    ///
+    /// The curve is a gaussian kernel density estimate of the samples, scaled to match the bars.
+    /// It follows counts, [`Histogram::set_fraction`] and [`Histogram::set_density`] alike.
+    /// By default, the bandwidth (how smooth the curve is) is picked with Silverman's rule of thumb.
+    /// Check out [`Histogram::set_kde_bandwidth`] for setting it yourself.
+    ///
+    /// # Accepted color conventions.
+    ///
+    /// * As its name such as "Red".
+    /// * As an RGB value such as "rgb(29, 28, 27)".
+    /// * As a HEX value such as "#1111FA".
+    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    ///
+    /// # Important
+    ///
+    /// The curve can not be drawn for cumulative histograms, adding one to a plot will panic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::{BarPlot, Histogram, HistogramBins};
+    ///
+    /// let samples = [12.1, 14.9, 13.3, 19.8, 11.0, 15.2, 16.7, 13.9, 14.4, 31.5];
+    /// let mut histogram = Histogram::new(&samples, HistogramBins::Width(2.0));
+    ///
+    /// histogram.set_density();
+    /// histogram.set_kde("Orange");
+    ///
+    /// let mut plot = BarPlot::new();
+    /// plot.add_histogram(&histogram);
+    /// plot.set_scale_range_auto();
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_kde(&mut self, color: &'a str) {
+        self.kde_color = Some(color);
+    }
+
+    /// Set the bandwidth for the kernel density estimate.
+    ///
+    /// The bandwidth is the standard deviation of the gaussian kernel, in the same unit as the samples.
+    /// A small bandwidth follows the samples closely, while a large bandwidth gives a smoother curve.
+    ///
+    /// Note: the curve is only drawn when [`Histogram::set_kde`] is called as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::{Histogram, HistogramBins};
+    ///
+    /// let samples = [12.1, 14.9, 13.3, 19.8, 11.0, 15.2, 16.7, 13.9, 14.4, 31.5];
+    /// let mut histogram = Histogram::new(&samples, HistogramBins::Width(2.0));
+    ///
+    /// histogram.set_kde("Orange");
+    /// histogram.set_kde_bandwidth(0.5);
+    /// ```
+    pub fn set_kde_bandwidth(&mut self, bandwidth: f64) {
+        assert!(bandwidth > 0.0, "The bandwidth must be greater than zero.");
+        self.kde_bandwidth = Some(bandwidth);
+    }
+
+    /// The bin edges, one more than the number of bins.
+    pub fn edges(&self) -> &[f64] {
+        &self.edges
+    }
+
+    /// Number of samples in each bin.
+    pub fn counts(&self) -> &[f64] {
+        &self.counts
+    }
+
+    /// The height of each bar, e.g. the counts after applying cumulative, fraction or density.
+    ///
+    /// Without any of these applied, the values are the same as [`Histogram::counts`].
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    fn update_values(&mut self) {
+        assert!(
+            !(matches!(self.normalize, Some(Normalize::Density)) && self.cumulative.is_some()),
+            "Can not combine a density histogram with a cumulative histogram.",
+        );
+
+        let mut values = self.counts.clone();
+
+        match self.cumulative {
+            Some(Cumulative::Forward) => {
+                for i in 1..values.len() {
+                    values[i] += values[i - 1];
+                }
+            }
+            Some(Cumulative::Reversed) => {
+                for i in (0..values.len() - 1).rev() {
+                    values[i] += values[i + 1];
+                }
+            }
+            None => (),
+        }
+
+        let total: f64 = self.counts.iter().sum();
+        // If no samples landed inside the edges, leave all bars at zero.
+        if total > 0.0 {
+            match self.normalize {
+                Some(Normalize::Fraction) => {
+                    values.iter_mut().for_each(|f| *f /= total);
+                }
+                Some(Normalize::Density) => {
+                    for (f, edge) in values.iter_mut().zip(self.edges.windows(2)) {
+                        *f /= total * (edge[1] - edge[0]);
+                    }
+                }
+                None => (),
+            }
+        }
+
+        self.values = values;
+    }
+
+    // The kernel density estimate as points of (bin position, value), where a bin position of 1.5 is the
+    // middle of the second bin. Values are scaled the same way as the bars.
+    pub(crate) fn kde_points(&self) -> Option<(Vec<(f64, f64)>, &'a str)> {
+        let color = self.kde_color?;
+        assert!(self.cumulative.is_none(), "Can not draw a kernel density estimate for a cumulative histogram.");
+
+        let bandwidth = self.kde_bandwidth.unwrap_or_else(|| silverman_bandwidth(&self.samples));
+        let norm = 1.0 / (bandwidth * (2.0 * std::f64::consts::PI).sqrt());
+        // Normalize by the binned samples just like the bars, as samples outside of the edges are not part of any bar.
+        // If no samples landed inside the edges, there is nothing to divide by.
+        let binned_total: f64 = self.counts.iter().sum::<f64>().max(1.0);
+
+        let (first, last) = (self.edges[0], self.edges[self.edges.len() - 1]);
+        let step = (last - first) / KDE_RESOLUTION as f64;
+
+        let points = (0..=KDE_RESOLUTION).map(|i| {
+            let x = first + (step * i as f64);
+            // Samples per unit, e.g. the density before it is divided by the number of samples.
+            let density: f64 = self.samples.iter().map(|f| (-0.5 * ((x - f) / bandwidth).powi(2)).exp()).sum::<f64>() * norm;
+
+            let bin_index = self.edges.partition_point(|edge| *edge <= x).saturating_sub(1).min(self.counts.len() - 1);
+            let bin_width = self.edges[bin_index + 1] - self.edges[bin_index];
+
+            // Scale the density the same way as the bars, e.g. back into counts or fractions.
+            let value = match self.normalize {
+                Some(Normalize::Density) => density / binned_total,
+                Some(Normalize::Fraction) => density * bin_width / binned_total,
+                None => density * bin_width,
+            };
+            let bin_pos = bin_index as f64 + ((x - self.edges[bin_index]) / bin_width);
+
+            (bin_pos, value)
+        }).collect();
+
+        Some((points, color))
+    }
+
+    pub(crate) fn markers(&self) -> Vec<&str> {
+        self.markers.iter().map(|s| s.as_str()).collect()
+    }
+}
+
+// Value below which a given fraction `q` of the sorted samples fall, interpolating between samples.
+pub(crate) fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let pos = (sorted.len() - 1) as f64 * q;
+    let (lower, upper) = (pos.floor() as usize, pos.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (pos - lower as f64)
+}
+
+fn std_dev(samples: &[f64]) -> f64 {
+    let n = samples.len() as f64;
+    let mean = samples.iter().sum::<f64>() / n;
+    (samples.iter().map(|f| (f - mean).powi(2)).sum::<f64>() / n).sqrt()
+}
+
+// Silverman's rule of thumb, falls back to 1 if the samples are all the same.
+fn silverman_bandwidth(sorted: &[f64]) -> f64 {
+    let n = sorted.len() as f64;
+    let std_dev = std_dev(sorted);
+    let iqr = quantile(sorted, 0.75) - quantile(sorted, 0.25);
+
+    let spread = if iqr > 0.0 { std_dev.min(iqr / 1.34) } else { std_dev };
+    let bandwidth = 0.9 * spread * n.powf(-0.2);
+    if bandwidth > 0.0 { bandwidth } else { 1.0 }
+}
+
+fn sturges_count(sorted: &[f64]) -> usize {
+    (sorted.len() as f64).log2().ceil() as usize + 1
+}
+
+// If the rule gives a width of zero (e.g. most samples are equal) or too many bins, fall back to Sturges' rule.
+fn count_from_width(sorted: &[f64], width: f64) -> usize {
+    let range = sorted[sorted.len() - 1] - sorted[0];
+    let count = (range / width).ceil().max(1.0);
+    if width > 0.0 && range > 0.0 && count <= MAX_RULE_BINS as f64 {
+        count as usize
+    } else {
+        sturges_count(sorted)
+    }
+}
+
+fn even_edges(sorted: &[f64], count: usize) -> Vec<f64> {
+    assert!(count > 0, "A histogram needs at least one bin.");
+
+    let (mut min, mut max) = (sorted[0], sorted[sorted.len() - 1]);
+    if min == max {
+        // All samples are equal, give the bins some room around them.
+        min -= 0.5;
+        max += 0.5;
+    }
+
+    let width = (max - min) / count as f64;
+    let mut edges: Vec<f64> = (0..count).map(|i| min + (width * i as f64)).collect();
+    edges.push(max);
+    edges
+}
+
+fn aligned_edges(sorted: &[f64], width: f64) -> Vec<f64> {
+    assert!(width > 0.0, "A histogram bin width must be greater than zero.");
+
+    let (min, max) = (sorted[0], sorted[sorted.len() - 1]);
+    let start = (min / width).floor() * width;
+    let count = ((max - start) / width).floor() as usize + 1;
+
+    (0..=count).map(|i| start + (width * i as f64)).collect()
+}
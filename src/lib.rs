@@ -71,6 +71,26 @@
 //!
 //! Check out [`BarPlot`] for all implementations.
 //!
+//! # Histograms
+//!
+//! If you have raw samples instead of values that are already counted, let [`Histogram`] sort them into bins.
+//! Check out [`HistogramBins`] for the available strategies and [`BarPlot::add_histogram`] for plotting the result.
+//!
+//! # Box plots
+//!
+//! If you have raw samples for each bin, let [`BoxPlot`] summarize them into quartiles, whiskers and outliers.
+//! Check out [`BoxWhiskers`] for how far the whiskers reach and [`BarPlot::add_box_plot`] for plotting the result.
+//!
+//! # Wind roses
+//!
+//! If you have wind samples (direction and speed), let [`WindRose`] sort them into direction sectors and speed classes.
+//! Check out [`BarPlot::add_wind_rose`] for plotting the result.
+//!
+//! # Figures
+//!
+//! If you have many plots that belong together, let [`Figure`] lay them out in a grid within a single svg.
+//! The plots can share a scale, bin markers and a legend, so that they line up and are easy to compare.
+//!
 //! # Panics and error handling.
 //!
 //! This library has very limited error handling at the moment. Actually, it has none.
@@ -81,7 +101,16 @@
 //! However, if you pass values that are generated from a source that you do not have full control over,
 //! then the task of making sure the input is sanitized and double checked lies on your end and your code.
 
+mod box_plot;
+mod figure;
+mod histogram;
 mod svg;
+mod wind_rose;
+
+pub use box_plot::{BoxPlot, BoxWhiskers};
+pub use figure::Figure;
+pub use histogram::{Histogram, HistogramBins};
+pub use wind_rose::WindRose;
 
 type Percentage = f64;
 
@@ -91,14 +120,18 @@ const REPOSITORY: &str = "https://github.com/emilbratt/eb_bars";
 const DEFAULT_SIZE: (u32, u32) = (1600, 1000);
 
 const DEFAULT_BAR_COLOR: &str = "rgb(112, 153, 182)";
+const DEFAULT_BAR_COLOR_BELOW: &str = "rgb(214, 126, 112)";
 const DEFAULT_BASE_COLOR: &str = "rgb(197, 197, 197)";
 const DEFAULT_BAR_GAP: Percentage = 0.0;
 const DEFAULT_BIN_GAP: Percentage = 10.0;
 
 const DEFAULT_FONT_SIZE: Percentage = 100.0;
 const DEFAULT_LEGEND_POSITION: (Percentage, Percentage) = (90.0, 20.0);
+const DEFAULT_PERCENT_STEP: f64 = 10.0;
 const DEFAULT_TEXT_SIDE_OFFSET: Percentage = 35.0;
 const DEFAULT_TICK_LENGTH: Percentage = 10.0;
+const DEFAULT_ERROR_BAR_CAP_WIDTH: Percentage = 50.0;
+const DEFAULT_ERROR_BAR_LINE_WIDTH: Percentage = 100.0;
 
 
 #[derive(Debug, Default)]
@@ -109,18 +142,50 @@ enum BinMarkerPosition {
     Right,
 }
 
+#[derive(Debug, Default)]
+enum BarOrientation {
+    #[default]
+    Vertical,
+    Horizontal,
+    Radial, // Bins go around a circle and bars grow outwards from the center.
+}
+
+#[derive(Debug, Default)]
+enum BarMode {
+    #[default]
+    Grouped, // Bars of each category are placed side by side in a bin.
+    Stacked, // Bars of each category are placed on top of each other in a bin.
+    StackedPercent, // Same as stacked, but each bar is scaled to its share of the bin total.
+    Waterfall, // Each value is a delta, floating from the running total of the previous bars.
+    Pareto, // Same as grouped, but bins are sorted in descending order with a cumulative percentage line on top.
+    Pyramid, // Two categories growing in opposite directions from the middle.
+    Bullet, // A narrow bar in front of wider qualitative ranges, with a target mark across it.
+    Timeline, // Floating bars of each category share the same row, spanning from a start to an end time.
+}
+
+#[derive(Debug, Default)]
+enum BarStyle {
+    #[default]
+    Rectangle, // Bars are filled rectangles.
+    Lollipop, // Bars are thin stems with a dot at the value.
+    Dumbbell, // The two bars in each bin are dots joined by a line.
+}
+
 #[derive(Debug, Default)]
 struct PlotLegend<'a> {
-    categories: Option<&'a[&'a str]>,
+    categories: Option<Vec<&'a str>>,
     position: Option<(Percentage, Percentage)>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum BarColorLayout<'a> {
     Category(Vec<&'a str>), // Each category has its own color.
     Indexed(Vec<Vec<&'a str>>), // Every bar has its own selected color.
     Threshold((&'a str, &'a str, &'a str, &'a str)), // Every bar is given its color based on its value.
     Uniform(&'a str), // All bars are the same color.
+    Waterfall((&'a str, &'a str, &'a str)), // Bars are given their color based on going up, down or being a total.
+    Baseline((&'a str, &'a str)), // Bars are given their color based on being above or below the baseline.
+    Candlestick((&'a str, &'a str)), // Bars are given their color based on closing above or below their opening.
 }
 
 impl Default for BarColorLayout<'_> {
@@ -129,7 +194,7 @@ impl Default for BarColorLayout<'_> {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 struct BarColors<'a> {
     layout: BarColorLayout<'a>,
     overrides: Vec<(usize, usize, &'a str)>,
@@ -139,6 +204,11 @@ struct BarColors<'a> {
 struct Colors<'a> {
     background: Option<&'a str>,
     bars: BarColors<'a>,
+    error_bars: &'a str,
+    pareto_line: &'a str,
+    bullet_target: &'a str,
+    sparkline_last_bar: Option<&'a str>,
+    sparkline_min_max: Option<&'a str>,
     line: &'a str,
     text: &'a str,
     tick: &'a str,
@@ -149,6 +219,11 @@ impl Default for Colors<'_> {
         Self {
             background: None,
             bars: BarColors::default(),
+            error_bars: DEFAULT_BASE_COLOR,
+            pareto_line: DEFAULT_BASE_COLOR,
+            bullet_target: DEFAULT_BASE_COLOR,
+            sparkline_last_bar: None,
+            sparkline_min_max: None,
             line: DEFAULT_BASE_COLOR,
             text: DEFAULT_BASE_COLOR,
             tick: DEFAULT_BASE_COLOR,
@@ -162,6 +237,8 @@ struct Show {
     plot_border: bool,
     horizontal_lines: bool,
     vertical_lines: bool,
+    stack_totals: bool,
+    waterfall_connectors: bool,
 }
 
 #[derive(Debug, Default)]
@@ -186,10 +263,21 @@ struct PlotLayout {
     bin_marker_position: BinMarkerPosition,
     font_size: Percentage,
     plot_window_scale: Option<(Percentage, Percentage, Percentage, Percentage)>,
-    scale_range: Option<(i64, i64, usize)>,
+    scale_range: Option<(f64, f64, f64)>,
+    scale_range_auto: bool,
     x_axis_tick_length: Percentage,
     y_axis_tick_length: Percentage,
     negative_bars_go_down: bool,
+    baseline: Option<f64>,
+    orientation: BarOrientation,
+    bar_mode: BarMode,
+    bar_style: BarStyle,
+    pyramid_gap: Option<Percentage>, // Room in the middle of a pyramid for bin markers.
+    bin_edges: Option<Vec<f64>>, // Where each bin starts and the last one ends, for bins of different widths.
+    radial_rotation: f64, // Degrees, clockwise.
+    time_axis: bool, // Scale values are seconds since the Unix epoch, labeled as dates and times.
+    error_bar_cap_width: Percentage,
+    error_bar_line_width: Percentage,
 }
 
 impl Default for PlotLayout {
@@ -201,9 +289,20 @@ impl Default for PlotLayout {
             font_size: DEFAULT_FONT_SIZE,
             plot_window_scale: None,
             scale_range: None,
+            scale_range_auto: false,
             x_axis_tick_length: DEFAULT_TICK_LENGTH,
             y_axis_tick_length: DEFAULT_TICK_LENGTH,
             negative_bars_go_down: false,
+            baseline: None,
+            orientation: BarOrientation::default(),
+            bar_mode: BarMode::default(),
+            bar_style: BarStyle::default(),
+            pyramid_gap: None,
+            bin_edges: None,
+            radial_rotation: 0.0,
+            time_axis: false,
+            error_bar_cap_width: DEFAULT_ERROR_BAR_CAP_WIDTH,
+            error_bar_line_width: DEFAULT_ERROR_BAR_LINE_WIDTH,
         }
     }
 }
@@ -212,13 +311,67 @@ impl Default for PlotLayout {
 enum LinesAt<'a> {
     Horizontal(f64, &'a str),
     Vertical(f64, &'a str),
+    Value(f64, &'a str), // Across the plot at a value on the scale.
+}
+
+#[derive(Debug)]
+struct LineSeries<'a> {
+    values: &'a [f64],
+    color: &'a str,
+    width: Percentage,
+    dash: Option<(Percentage, Percentage)>, // Dash and gap length.
+    point_markers: bool,
+}
+
+#[derive(Debug, Default)]
+enum PointShape {
+    #[default]
+    Circle,
+    Square,
+    Diamond,
+    Cross,
+}
+
+#[derive(Debug)]
+struct Points<'a> {
+    bins: &'a [usize],
+    values: &'a [f64],
+    color: &'a str,
+    shape: PointShape,
+    size: Percentage,
+    jitter: Percentage, // How far points can be spread sideways, as a percentage of the bin width.
+}
+
+#[derive(Debug)]
+enum ErrorBars<'a> {
+    Symmetric(&'a [f64]), // Plus minus value for each bar.
+    Bounds(&'a [f64], &'a [f64]), // Lower and upper value for each bar.
+}
+
+// A curve drawn over the bars, where each point is (bin position, value).
+// A bin position of 0 is the start of the first bin, 1.5 is the middle of the second bin and so on.
+#[derive(Debug)]
+struct Curve<'a> {
+    points: Vec<(f64, f64)>,
+    color: &'a str,
 }
 
 #[derive(Debug)]
 pub struct BarPlot<'a> {
     values: Vec<&'a [f64]>,
-    markers: Option<&'a [&'a str]>,
+    range_starts: Vec<Option<&'a [f64]>>, // One for each set of values, only set for floating bars.
+    stack_groups: Vec<usize>, // One for each set of values, deciding which stack in a bin they go into.
+    wicks: Vec<Option<(&'a [f64], &'a [f64])>>, // One for each set of values (low, high), only set for candlesticks.
+    markers: Option<Vec<&'a str>>,
     lines_at: Vec<LinesAt<'a>>,
+    curves: Vec<Curve<'a>>,
+    line_series: Vec<LineSeries<'a>>,
+    points: Vec<Points<'a>>,
+    error_bars: Vec<(usize, ErrorBars<'a>)>, // Category index and its error bars.
+    waterfall_totals: Option<&'a [usize]>,
+    bullet_ranges: Vec<(&'a [f64], &'a str)>, // Upper end of each range for every bin, and its color.
+    bullet_targets: Option<&'a [f64]>,
+    box_plots: Vec<(usize, &'a BoxPlot)>, // Category index and its box plot.
     size: (u32, u32),
     colors: Colors<'a>,
     legend: PlotLegend<'a>,
@@ -243,8 +396,19 @@ impl <'a>BarPlot<'a> {
     pub fn new() -> Self {
         Self {
             values: Vec::new(),
+            range_starts: Vec::new(),
+            stack_groups: Vec::new(),
+            wicks: Vec::new(),
             markers: None,
             lines_at: Vec::new(),
+            curves: Vec::new(),
+            line_series: Vec::new(),
+            points: Vec::new(),
+            error_bars: Vec::new(),
+            waterfall_totals: None,
+            bullet_ranges: Vec::new(),
+            bullet_targets: None,
+            box_plots: Vec::new(),
             size: DEFAULT_SIZE,
             colors: Colors::default(),
             legend: PlotLegend::default(),
@@ -314,19 +478,28 @@ impl <'a>BarPlot<'a> {
             );
         }
         self.values.push(values);
+        self.range_starts.push(None);
+        self.stack_groups.push(0);
+        self.wicks.push(None);
     }
 
-    /// Set a fill color as background.
+    /// Adding a set of floating bars (ranges) to the plot.
     ///
-    /// By default, the image will be fully transparent where there is nothing drawn on it.
-    /// Adding a background color might be a good idea for better visual presentation depending on usecase.
+    /// Instead of growing from the floor (or from zero), each bar floats between its low and high value.
+    /// Typical uses are minimum and maximum temperature for each day, price ranges and time windows.
     ///
-    /// # Accepted color conventions.
+    /// # How it operates
     ///
-    /// * As its name such as "Red".
-    /// * As an RGB value such as "rgb(29, 28, 27)".
-    /// * As a HEX value such as "#1111FA".
-    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    /// This works just like [`BarPlot::add_values`], where the high values are the values of the bars.
+    /// That means colors, legend and bin markers work the same way, and you can mix floating bars with
+    /// regular bars by calling both methods. Error bars (see [`BarPlot::add_error_bars`]) are drawn at the high end.
+    ///
+    /// Both arrays must be of the `exact` same length, and the same length as any other values added.
+    /// If a low value is greater than its high value, the bar simply spans between the two.
+    ///
+    /// # Important
+    ///
+    /// Floating bars can not be stacked, see [`BarPlot::set_bars_stacked`].
     ///
     /// # Example
     ///
@@ -335,27 +508,49 @@ impl <'a>BarPlot<'a> {
     ///
     /// let mut plot = BarPlot::new();
     ///
-    /// plot.add_values(&[1., 2., 3.,]);
+    /// let min_temperature = [-4.2, -1.3, 2.8, 6.1];
+    /// let max_temperature = [3.1, 5.8, 11.0, 16.4];
     ///
-    /// plot.set_background_color("Black");
+    /// plot.add_ranges(&min_temperature, &max_temperature);
+    ///
+    /// plot.set_scale_range(-10, 20, 5);
     ///
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
-    pub fn set_background_color(&mut self, color: &'a str) {
-        self.colors.background = Some(color);
+    pub fn add_ranges(&mut self, low: &'a [f64], high: &'a [f64]) {
+        let (low_count, high_count) = (low.len(), high.len());
+        assert_eq!(
+            low_count,
+            high_count,
+            "Low and high values should be same count, got {low_count} low and {high_count} high"
+        );
+
+        self.add_values(high);
+        if let Some(start) = self.range_starts.last_mut() {
+            *start = Some(low);
+        }
     }
 
-    /// Set color for the lines.
+    /// Adding a set of candlesticks to the plot, from the open, high, low and close value of each bin.
     ///
-    /// By default, all lines are drawn with a `default` color.
-    /// You can `override` this by setting your own color.
+    /// Each bin gets a body floating between the open and close value, and a thin wick from the low to the high value.
+    /// Bins that closed at or above their opening are drawn in one color, and bins that closed below in another.
+    /// This is how stock prices are usually shown, with one bin for each day (or hour, or week).
     ///
-    /// # Accepted color conventions.
+    /// # How it operates
     ///
-    /// * As its name such as "Red".
-    /// * As an RGB value such as "rgb(29, 28, 27)".
-    /// * As a HEX value such as "#1111FA".
-    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    /// The bodies are floating bars (see [`BarPlot::add_ranges`]) from the open to the close value,
+    /// and the wicks are drawn behind them in the same color. The scale makes room for the wicks as well.
+    ///
+    /// Bars are colored by rising and falling with two default colors, unless other bar colors are set.
+    /// Check out [`BarPlot::set_bar_colors_by_candlestick`] for setting your own colors.
+    ///
+    /// All four arrays must be of the `exact` same length, and the same length as any other values added.
+    ///
+    /// # Important
+    ///
+    /// The low value must not be above the open or close value, and the high value must not be below them.
+    /// Candlesticks can not be stacked, nor drawn as a radial chart.
     ///
     /// # Example
     ///
@@ -364,56 +559,109 @@ impl <'a>BarPlot<'a> {
     ///
     /// let mut plot = BarPlot::new();
     ///
-    /// plot.add_values(&[1., 2., 3.,]);
+    /// let open = [101.2, 103.8, 102.9, 99.4];
+    /// let high = [104.5, 105.1, 103.3, 102.8];
+    /// let low = [100.3, 102.2, 98.7, 98.9];
+    /// let close = [103.8, 102.9, 99.4, 102.1];
     ///
-    /// plot.set_line_color("Yellow");
+    /// plot.add_candlesticks(&open, &high, &low, &close);
+    ///
+    /// plot.set_scale_range(95, 110, 5);
     ///
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
-    pub fn set_line_color(&mut self, color: &'a str) {
-        self.colors.line = color;
+    pub fn add_candlesticks(&mut self, open: &'a [f64], high: &'a [f64], low: &'a [f64], close: &'a [f64]) {
+        let count = open.len();
+        assert!(
+            [high.len(), low.len(), close.len()].iter().all(|n| *n == count),
+            "Open, high, low and close values should be same count, got {count} open, {} high, {} low and {} close",
+            high.len(),
+            low.len(),
+            close.len(),
+        );
+        for i in 0..count {
+            let (body_low, body_high) = (open[i].min(close[i]), open[i].max(close[i]));
+            assert!(
+                low[i] <= body_low && high[i] >= body_high,
+                "The low and high value must span the open and close value, not so for bin {i}.",
+            );
+        }
+
+        self.add_ranges(open, close);
+        if let Some(wick) = self.wicks.last_mut() {
+            *wick = Some((low, high));
+        }
+
+        if let BarColorLayout::Uniform(DEFAULT_BAR_COLOR) = self.colors.bars.layout {
+            self.colors.bars.layout = BarColorLayout::Candlestick((DEFAULT_BAR_COLOR, DEFAULT_BAR_COLOR_BELOW));
+        }
     }
 
-    /// Set color for text/numbers.
+    /// Adding a histogram (binned samples) to the plot.
     ///
-    /// By default, all text are drawn with a `default` color.
-    /// You can `override` this by setting your own color.
+    /// The values from the [`Histogram`] are added as a set of values, the same way as with [`BarPlot::add_values`].
+    /// These are the counts, or the running totals, fractions or densities if any of these are applied to the histogram.
+    /// If the histogram has a kernel density estimate (see [`Histogram::set_kde`]), its curve is drawn over the bars.
+    /// The bin edges are set as bin markers, placed at the left side of each bin (the last one closing off the plot).
+    /// This means there is no need to call [`BarPlot::set_bin_markers`] or [`BarPlot::set_bin_markers_left`].
+    /// The bin edges are also set with [`BarPlot::set_bin_edges`], so bins of unequal width are drawn to scale.
+    /// If no scale is set, [`BarPlot::set_scale_range_auto`] is applied, so that the bars grow out from zero.
     ///
-    /// # Accepted color conventions.
+    /// # Comparing distributions
     ///
-    /// * As its name such as "Red".
-    /// * As an RGB value such as "rgb(29, 28, 27)".
-    /// * As a HEX value such as "#1111FA".
-    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    /// Calling this method more than once works just like calling [`BarPlot::add_values`] more than once.
+    /// Make sure that all histograms are created with the same bins, e.g. by using [`HistogramBins::Edges`].
+    /// The bin markers are taken from the histogram added last.
     ///
     /// # Example
     ///
     /// ```
-    /// use eb_bars::BarPlot;
+    /// use eb_bars::{BarPlot, Histogram, HistogramBins};
     ///
     /// let mut plot = BarPlot::new();
     ///
-    /// plot.add_values(&[1., 2., 3.,]);
+    /// let response_times = [12.1, 14.9, 13.3, 19.8, 11.0, 15.2, 16.7, 13.9, 14.4, 31.5];
+    /// let histogram = Histogram::new(&response_times, HistogramBins::Sturges);
     ///
-    /// plot.set_text_color("LightBlue");
+    /// plot.add_histogram(&histogram);
+    /// plot.set_bin_gap(0.0);
     ///
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
-    pub fn set_text_color(&mut self, color: &'a str) {
-        self.colors.text = color;
+    pub fn add_histogram(&mut self, histogram: &'a Histogram<'a>) {
+        self.add_values(histogram.values());
+        self.markers = Some(histogram.markers());
+        self.layout.bin_marker_position = BinMarkerPosition::Left;
+        self.layout.bin_edges = Some(histogram.edges().to_vec());
+
+        if let Some((points, color)) = histogram.kde_points() {
+            self.curves.push(Curve { points, color });
+        }
+
+        if self.layout.scale_range.is_none() {
+            self.layout.scale_range_auto = true;
+        }
     }
 
-    /// Set color for x-ticks and y-ticks.
+    /// Adding a set of tasks to a timeline (Gantt chart), each spanning from a start to an end time.
     ///
-    /// By default, all ticks are drawn with a `default` color.
-    /// You can `override` this by setting your own color.
+    /// Each bin is a row (a task or a resource), and each task is a horizontal bar from its start to its end time.
+    /// Calling this method again adds another set of tasks to the same rows, e.g. a second shift for each person
+    /// in an on-call rotation. Use `f64::NAN` as start and end to leave a row empty in a set.
     ///
-    /// # Accepted color conventions.
+    /// # How it operates
     ///
-    /// * As its name such as "Red".
-    /// * As an RGB value such as "rgb(29, 28, 27)".
-    /// * As a HEX value such as "#1111FA".
-    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    /// Times are given in seconds since the Unix epoch (UTC), e.g. `timestamp()` from the `chrono` crate.
+    /// The scale is labeled with dates and times (in UTC), by day if the step is a day or longer,
+    /// otherwise by the time of day. To show local time, shift all times by your offset from UTC.
+    ///
+    /// The bars are drawn horizontally with one row per bin (see [`BarPlot::set_bin_markers`] for naming the rows).
+    /// If no scale is set, one is found from the times, with a step such as 15 minutes, 1 hour or 1 day.
+    /// When setting a scale with [`BarPlot::set_scale_range`], pass it in seconds as well.
+    ///
+    /// Each set of tasks has its own color (see [`BarPlot::add_bar_colors_by_category`]),
+    /// and single tasks can be colored with [`BarPlot::add_bar_color_override`].
+    /// Check out [`BarPlot::add_scale_line_at`] for marking "now".
     ///
     /// # Example
     ///
@@ -422,112 +670,165 @@ impl <'a>BarPlot<'a> {
     ///
     /// let mut plot = BarPlot::new();
     ///
-    /// plot.add_values(&[1., 2., 3.,]);
+    /// // Three batch jobs on 2025-03-01, times in seconds since the Unix epoch.
+    /// let midnight = 1_740_787_200.0;
+    /// let hour = 3600.0;
+    /// let start = [midnight + 1.0 * hour, midnight + 2.5 * hour, midnight + 4.0 * hour];
+    /// let end = [midnight + 3.0 * hour, midnight + 5.0 * hour, midnight + 4.5 * hour];
     ///
-    /// plot.set_tick_color("LightBlue");
+    /// plot.add_timeline(&start, &end);
+    /// plot.set_bin_markers(&["Backup", "Reindex", "Report"]);
+    ///
+    /// // Mark "now" with a line.
+    /// plot.add_scale_line_at(midnight + 3.5 * hour, "Red");
     ///
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
-    pub fn set_tick_color(&mut self, color: &'a str) {
-        self.colors.tick = color;
+    pub fn add_timeline(&mut self, start: &'a [f64], end: &'a [f64]) {
+        self.add_ranges(start, end);
+        self.layout.bar_mode = BarMode::Timeline;
+        self.layout.orientation = BarOrientation::Horizontal;
+        self.layout.time_axis = true;
+        if self.layout.scale_range.is_none() {
+            self.layout.scale_range_auto = true;
+        }
     }
 
-    /// Set a single color for all bars.
+    /// Adding a box plot (quartiles, whiskers and outliers of raw samples) to the plot.
     ///
-    /// By default, all bars are drawn with a `default` color.
-    /// You can `override` this by setting a new uniform color value.
+    /// Each bin gets a box from the first to the third quartile, a line across it at the median,
+    /// whiskers reaching out from the box and a dot for each outlier beyond the whiskers.
     ///
-    /// # Accepted color conventions.
+    /// # How it operates
     ///
-    /// * As its name such as "Red".
-    /// * As an RGB value such as "rgb(29, 28, 27)".
-    /// * As a HEX value such as "#1111FA".
-    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    /// The boxes are floating bars (see [`BarPlot::add_ranges`]), so adding more than one [`BoxPlot`]
+    /// places the boxes side by side in each bin, the same way as with [`BarPlot::add_values`].
+    /// Boxes, whiskers and outliers take the color of their category (see [`BarPlot::add_bar_colors_by_category`]),
+    /// while the median line takes the line color (see [`BarPlot::set_line_color`]).
+    ///
+    /// The scale makes room for the whiskers. For the outliers, use [`BarPlot::set_scale_range_auto`]
+    /// or set a scale that covers them with [`BarPlot::set_scale_range`].
+    ///
+    /// # Important
+    ///
+    /// Box plots can not be stacked, nor drawn as a radial chart.
     ///
     /// # Example
     ///
     /// ```
-    /// use eb_bars::BarPlot;
+    /// use eb_bars::{BarPlot, BoxPlot, BoxWhiskers};
     ///
-    /// let mut plot = BarPlot::new();
+    /// let morning = [12.0, 15.0, 14.0, 13.0, 41.0, 16.0];
+    /// let evening = [18.0, 16.0, 21.0, 19.0, 22.0];
     ///
-    /// plot.add_values(&[1., 2., 3.,]);
+    /// let box_plot = BoxPlot::new(&[&morning, &evening], BoxWhiskers::Iqr(1.5));
     ///
-    /// plot.set_bar_colors_by_uniform("Green");
+    /// let mut plot = BarPlot::new();
+    /// plot.add_box_plot(&box_plot);
+    /// plot.set_bin_markers(&["Morning", "Evening"]);
+    /// plot.set_scale_range_auto();
     ///
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
-    pub fn set_bar_colors_by_uniform(&mut self, color: &'a str) {
-        self.colors.bars.layout = BarColorLayout::Uniform(color);
+    pub fn add_box_plot(&mut self, box_plot: &'a BoxPlot) {
+        self.add_ranges(box_plot.lower_quartiles(), box_plot.upper_quartiles());
+        if let Some(wick) = self.wicks.last_mut() {
+            *wick = Some((box_plot.lower_whiskers(), box_plot.upper_whiskers()));
+        }
+        self.box_plots.push((self.values.len() - 1, box_plot));
     }
 
-    /// Set bar colors by threshold.
+    /// Adding a wind rose (wind samples sorted by direction and speed) to the plot.
     ///
-    /// By default, all bars are drawn with a `default` color.
-    /// You can `override` this by setting different colors for different thresholds.
-    /// The threshold is as follows: minumum value, less than average, greater than or equal to average and max.
-    ///
-    /// # Accepted color conventions.
-    ///
-    /// * As its name such as "Red".
-    /// * As an RGB value such as "rgb(29, 28, 27)".
-    /// * As a HEX value such as "#1111FA".
-    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    /// Each speed class of the [`WindRose`] is added as a set of values, the same way as with [`BarPlot::add_values`],
+    /// slowest first. The bars are stacked (see [`BarPlot::set_bars_stacked`]) and drawn around a circle
+    /// (see [`BarPlot::set_orientation_radial`]), with each sector centered on its direction.
+    /// The sector names are set as bin markers and the speed classes as legend.
     ///
-    /// # Avoid if having more than one set of values.
+    /// # Colors
     ///
-    /// When you add multiple sets of values e.g. calling [`BarPlot::add_values`] multiple times,
-    /// then you might want to use [`BarPlot::add_bar_colors_by_category`] instead.
+    /// Give each speed class a color by calling [`BarPlot::add_bar_colors_by_category`] once for every speed class,
+    /// slowest first, or use any of the other bar color methods.
     ///
     /// # Example
     ///
     /// ```
-    /// use eb_bars::BarPlot;
+    /// use eb_bars::{BarPlot, WindRose};
     ///
     /// let mut plot = BarPlot::new();
     ///
-    /// plot.add_values(&[1., 2., 3.,]);
+    /// let samples = [(200.0, 4.1), (215.0, 7.8), (190.0, 11.2), (20.0, 2.4), (250.0, 5.5), (230.0, 0.3)];
+    /// let wind_rose = WindRose::new(&samples, 16, &[0.5, 3.0, 6.0, 10.0]);
     ///
-    /// // Each color represent how signifacant a value is.
-    /// let min = "Red"; // The lowest value will have its bar colored red.
-    /// let low = "Orange"; // Low values will have their bars be orange.
-    /// let high = "Yellow"; // High values will be yellow.
-    /// let max = "Green"; // Max value (tallest bar) will be green.
+    /// plot.add_wind_rose(&wind_rose);
+    /// for color in ["LightBlue", "LightGreen", "Yellow", "Red"] {
+    ///     plot.add_bar_colors_by_category(color);
+    /// }
     ///
-    /// plot.set_bar_colors_by_threshold(min, low, high, max);
+    /// plot.set_scale_range(0, 40, 10);
+    /// plot.set_show_horizontal_lines();
+    /// plot.set_plot_window_size(80.0, 50.0, 80.0, 50.0);
     ///
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
-    pub fn set_bar_colors_by_threshold(&mut self, min: &'a str, low: &'a str, high: &'a str, max: &'a str) {
-        self.colors.bars.layout = BarColorLayout::Threshold((min, low, high, max));
+    pub fn add_wind_rose(&mut self, wind_rose: &'a WindRose) {
+        for values in wind_rose.values() {
+            self.add_values(values);
+        }
+        self.markers = Some(wind_rose.markers());
+        self.layout.bin_marker_position = BinMarkerPosition::Middle;
+        self.legend.categories = Some(wind_rose.legend());
+
+        self.layout.bar_mode = BarMode::Stacked;
+        self.layout.orientation = BarOrientation::Radial;
+        // Turn the circle back by half a sector, so that the first sector is centered on north.
+        self.layout.radial_rotation = -180.0 / wind_rose.values()[0].len() as f64;
     }
 
-    /// Add color to last added values.
+    /// Add error bars to the last added values.
     ///
-    /// By default, all bars are drawn with a `default` color.
-    /// You can `override` this by setting different colors for different categories.
-    /// Keep in mind that this only make sens to use if you have called [`BarPlot::add_values`]
-    /// at least 2 times. The two datasets are treated as two distinct categories.
-    /// Note: see [`BarPlot::set_legend`] for displaying the names with their respective color.
+    /// Each bar gets a whisker spanning from `value - error` to `value + error`, with a cap at both ends.
+    /// This is typically used for showing the standard deviation or a margin of error.
     ///
     /// # How it operates
-    /// The category added first will have its bars colored first.
-    /// Then, any consecutive call will apply proportional to all consecutive calls to [`BarPlot::add_values`].
     ///
-    /// In simple terms: if you call [`BarPlot::add_values`] 5 times, then it is required to call this method
-    /// `exactly` 5 times. Each time with its own particular color.
+    /// The errors apply to the set of values added last with [`BarPlot::add_values`],
+    /// so call this right after adding the values it belongs to. Categories without error bars are fine.
+    /// The array of errors must be of the same length as the values.
     ///
-    /// # Accepted color conventions.
+    /// With stacked bars (see [`BarPlot::set_bars_stacked`]), the whisker is centered on the end of each bar in the stack.
     ///
-    /// * As its name such as "Red".
-    /// * As an RGB value such as "rgb(29, 28, 27)".
-    /// * As a HEX value such as "#1111FA".
-    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    /// Note: check out [`BarPlot::add_error_bounds`] for uneven errors and [`BarPlot::set_error_bar_color`],
+    /// [`BarPlot::set_error_bar_cap_width`] and [`BarPlot::set_error_bar_line_width`] for styling.
     ///
-    /// # Avoid if having only one set of values.
+    /// # Example
     ///
-    /// If you only add one set of values e.g. calling [`BarPlot::add_values`] one time,
-    /// then it makes no sense using this method. Use any of the other ways to set colors.
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// let mean_run_time = [12.4, 15.1, 9.8];
+    /// let std_dev = [1.1, 2.3, 0.7];
+    ///
+    /// plot.add_values(&mean_run_time);
+    /// plot.add_error_bars(&std_dev);
+    ///
+    /// plot.set_scale_range(0, 20, 2);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn add_error_bars(&mut self, errors: &'a [f64]) {
+        let category = self.assert_error_bar_count(errors);
+        self.error_bars.push((category, ErrorBars::Symmetric(errors)));
+    }
+
+    /// Add error bars with separate lower and upper bounds to the last added values.
+    ///
+    /// Works like [`BarPlot::add_error_bars`], except that each whisker spans from the given lower value
+    /// to the given upper value instead of an equal distance in both directions.
+    /// The bounds are values on the same scale as the bars, not distances from the bar,
+    /// which makes it a good fit for confidence intervals.
     ///
     /// # Example
     ///
@@ -536,40 +837,35 @@ impl <'a>BarPlot<'a> {
     ///
     /// let mut plot = BarPlot::new();
     ///
-    /// let apples: Vec<f64> = vec![5., 16., 17., 8., 3.];
-    /// let oranges: Vec<f64> = vec![7., 6., 7., 16., 9.];
+    /// let conversion_rate = [4.2, 5.1, 3.3];
+    /// let lower = [3.9, 4.2, 3.1];
+    /// let upper = [4.8, 5.4, 4.0];
     ///
-    /// // Add first set of values.
-    /// plot.add_values(&apples);
-    /// // Adding a second set of values.
-    /// plot.add_values(&oranges);
+    /// plot.add_values(&conversion_rate);
+    /// plot.add_error_bounds(&lower, &upper);
     ///
-    /// // First call adds a color to the first category. Red bacuse apples are often red.
-    /// plot.add_bar_colors_by_category("Red");
-    /// // Second call adds a color to the second category. Orange because; oranges are orange. :)
-    /// plot.add_bar_colors_by_category("Orange");
+    /// plot.set_scale_range(0, 6, 1);
     ///
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
-    pub fn add_bar_colors_by_category(&mut self, color: &'a str) {
-        if let BarColorLayout::Category(v) = &mut self.colors.bars.layout {
-            v.push(color);
-        } else {
-            self.colors.bars.layout = BarColorLayout::Category(vec![color]);
-        }
+    pub fn add_error_bounds(&mut self, lower: &'a [f64], upper: &'a [f64]) {
+        let category = self.assert_error_bar_count(lower);
+        self.assert_error_bar_count(upper);
+        self.error_bars.push((category, ErrorBars::Bounds(lower, upper)));
     }
 
-    /// Add a set of colors for every bar in last added category.
+    /// Add a line going through the middle of each bin, on the same scale as the bars.
     ///
-    /// By default, all bars are drawn with a `default` color.
-    /// You can `override` this by adding an array of colors which have same length as the values.
+    /// This is useful for drawing e.g. a target or a forecast on top of the actual values.
+    /// The line is drawn on top of the bars and does not take part in the legend.
     ///
     /// # How it operates
-    /// The category added first will have its bars colored first.
-    /// Then, any consecutive call will apply proportional to all consecutive calls to [`BarPlot::add_values`].
     ///
-    /// In simple terms: if you call [`BarPlot::add_values`] 5 times, then it is required to call this method
-    /// `exactly` 5 times. Each time with its own particular set of colors.
+    /// There must be one value for each bin. Call this as many times as you want, each call adds a new line.
+    /// If the scale is set with [`BarPlot::set_scale_range_auto`], the lines are included when finding the scale.
+    ///
+    /// Note: check out [`BarPlot::set_line_series_width`], [`BarPlot::set_line_series_dash`] and
+    /// [`BarPlot::set_line_series_point_markers`] for styling the last added line.
     ///
     /// # Accepted color conventions.
     ///
@@ -578,8 +874,6 @@ impl <'a>BarPlot<'a> {
     /// * As a HEX value such as "#1111FA".
     /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
     ///
-    /// This method is meant for users that want full control over the bar colors.
-    ///
     /// # Example
     ///
     /// ```
@@ -587,57 +881,1710 @@ impl <'a>BarPlot<'a> {
     ///
     /// let mut plot = BarPlot::new();
     ///
-    /// let apples: Vec<f64> = vec![5., 16., 17., 8., 3.];
-    /// let oranges: Vec<f64> = vec![7., 6., 7., 16., 9.];
+    /// let sales = [42., 51., 38., 60.];
+    /// let target = [45., 45., 50., 50.];
     ///
-    /// // Add first set of values.
-    /// plot.add_values(&apples);
-    /// // Adding a second set of values.
-    /// plot.add_values(&oranges);
+    /// plot.add_values(&sales);
+    /// plot.add_line_series(&target, "Red");
     ///
-    /// // First call adds a color to the first category. Red bacuse apples are often red.
-    /// let clr_red = vec!["Red", "Red", "Red", "Red", "Red"];
-    /// plot.add_bar_colors_from_vec(clr_red);
-    /// // Second call adds a color to the second category. Orange because; oranges are orange. :)
-    /// let clr_orange = vec!["Orange", "Orange", "Orange", "Orange", "Orange"];
-    /// plot.add_bar_colors_from_vec(clr_orange);
+    /// plot.set_scale_range(0, 70, 10);
     ///
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
-    pub fn add_bar_colors_from_vec(&mut self, colors: Vec<&'a str>) {
-        if let BarColorLayout::Indexed(v) = &mut self.colors.bars.layout {
-            v.push(colors);
-        } else {
-            self.colors.bars.layout = BarColorLayout::Indexed(vec![colors]);
-        }
+    pub fn add_line_series(&mut self, values: &'a [f64], color: &'a str) {
+        self.line_series.push(LineSeries { values, color, width: 100.0, dash: None, point_markers: false });
     }
 
-    /// Override any bar with any color.
+    /// Set line width for the last added line series.
     ///
-    /// This one will override the category index and the value (bar) index with a color.
-    /// Say you added 3 sets of values by calling [`BarPlot::add_values`] 3 times.
-    /// This means you have 3 categories times `X` values where `X` is the length of array holding
-    /// each of set of values.
+    /// The width is calculated using a percentage.
+    /// A width of 100 (100%) will not affect the line width as it is the default.
+    /// You can either increase the width by passing a >100 value or decrease it with <100.
     ///
-    /// # How it operates
-    /// The category added first will have its category and bars colored zero indexed.
-    /// The first bar in the first category will have index 0-0. The next bar will have 0-1.
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[42., 51., 38., 60.]);
+    /// plot.add_line_series(&[45., 45., 50., 50.], "Red");
+    ///
+    /// let width = 50.0; // Half as thick as default.
+    /// plot.set_line_series_width(width);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_line_series_width(&mut self, p: Percentage) {
+        self.last_line_series().width = p;
+    }
+
+    /// Draw the last added line series as a dashed line.
+    ///
+    /// Both the dash and the gap between dashes are calculated using a percentage of the bin width.
+    /// E.g. passing 25 and 25 will give two dashes per bin.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// let actual = [42., 51., 38., 60.];
+    /// let forecast = [40., 48., 47., 55.];
+    ///
+    /// plot.add_values(&actual);
+    /// plot.add_line_series(&forecast, "LightBlue");
+    ///
+    /// let dash = 10.0;
+    /// let gap = 5.0;
+    /// plot.set_line_series_dash(dash, gap);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_line_series_dash(&mut self, dash: Percentage, gap: Percentage) {
+        assert!(dash > 0.0 && gap > 0.0, "Dash and gap must be larger than zero, got {dash} and {gap}.");
+        self.last_line_series().dash = Some((dash, gap));
+    }
+
+    /// Draw a dot on each point of the last added line series.
+    ///
+    /// The dots use the same color as the line and grow along with the line width.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[42., 51., 38., 60.]);
+    /// plot.add_line_series(&[45., 45., 50., 50.], "Red");
+    ///
+    /// plot.set_line_series_point_markers();
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_line_series_point_markers(&mut self) {
+        self.last_line_series().point_markers = true;
+    }
+
+    fn last_line_series(&mut self) -> &mut LineSeries<'a> {
+        self.line_series.last_mut().expect("Can not style a line series before adding one.")
+    }
+
+    /// Add points on top of the bars, placed by bin and value.
+    ///
+    /// Each point is drawn in the middle of the given bin (zero indexed), at the given value on the same scale as the bars.
+    /// This is useful for showing the raw observations behind each bar, or for pointing out outliers.
+    ///
+    /// # How it operates
+    ///
+    /// The bins and values are paired up, so the two arrays must be of the same length.
+    /// A bin can have as many points as you want. Call this as many times as you want, each call adds a new group of points.
+    /// If the scale is set with [`BarPlot::set_scale_range_auto`], the points are included when finding the scale.
+    ///
+    /// Points are drawn as circles by default.
+    /// Note: check out [`BarPlot::set_points_shape_square`], [`BarPlot::set_points_shape_diamond`],
+    /// [`BarPlot::set_points_shape_cross`], [`BarPlot::set_points_size`] and [`BarPlot::set_points_jitter`]
+    /// for styling the last added points.
+    ///
+    /// # Accepted color conventions.
+    ///
+    /// * As its name such as "Red".
+    /// * As an RGB value such as "rgb(29, 28, 27)".
+    /// * As a HEX value such as "#1111FA".
+    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// let mean = [5.0, 7.0];
+    /// let bins = [0, 0, 0, 1, 1, 1];
+    /// let observations = [4.1, 5.2, 5.7, 6.4, 7.0, 7.6];
+    ///
+    /// plot.add_values(&mean);
+    /// plot.add_points(&bins, &observations, "White");
+    ///
+    /// plot.set_scale_range(0, 10, 1);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn add_points(&mut self, bins: &'a [usize], values: &'a [f64], color: &'a str) {
+        let (n_bins, n_values) = (bins.len(), values.len());
+        assert_eq!(n_bins, n_values, "Got {n_bins} bins and {n_values} values for the points.");
+
+        let points = Points { bins, values, color, shape: PointShape::default(), size: 100.0, jitter: 0.0 };
+        self.points.push(points);
+    }
+
+    /// Draw the last added points as circles.
+    ///
+    /// This is the default, so there is no need to call this unless you want to be explicit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[5.0, 7.0]);
+    /// plot.add_points(&[0, 1], &[6.2, 4.9], "White");
+    ///
+    /// plot.set_points_shape_circle();
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_points_shape_circle(&mut self) {
+        self.last_points().shape = PointShape::Circle;
+    }
+
+    /// Draw the last added points as squares.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[5.0, 7.0]);
+    /// plot.add_points(&[0, 1], &[6.2, 4.9], "White");
+    ///
+    /// plot.set_points_shape_square();
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_points_shape_square(&mut self) {
+        self.last_points().shape = PointShape::Square;
+    }
+
+    /// Draw the last added points as diamonds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[5.0, 7.0]);
+    /// plot.add_points(&[0, 1], &[6.2, 4.9], "White");
+    ///
+    /// plot.set_points_shape_diamond();
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_points_shape_diamond(&mut self) {
+        self.last_points().shape = PointShape::Diamond;
+    }
+
+    /// Draw the last added points as crosses (x).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[5.0, 7.0]);
+    /// plot.add_points(&[0, 1], &[6.2, 4.9], "White");
+    ///
+    /// plot.set_points_shape_cross();
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_points_shape_cross(&mut self) {
+        self.last_points().shape = PointShape::Cross;
+    }
+
+    /// Set size for the last added points.
+    ///
+    /// The size is calculated using a percentage.
+    /// A size of 100 (100%) will not affect the size as it is the default.
+    /// You can either increase the size by passing a >100 value or decrease it with <100.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[5.0, 7.0]);
+    /// plot.add_points(&[0, 1], &[6.2, 4.9], "White");
+    ///
+    /// let size = 150.0; // One and a half times as large as default.
+    /// plot.set_points_size(size);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_points_size(&mut self, p: Percentage) {
+        self.last_points().size = p;
+    }
+
+    /// Spread the last added points sideways, so that points with similar values do not hide each other.
+    ///
+    /// The spread is calculated using a percentage of the bin width.
+    /// E.g. passing 50 lets points move a quarter of the bin width to either side of the bin middle.
+    /// The spread looks random, but is the same every time the plot is rendered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[5.0, 7.0]);
+    /// plot.add_points(&[0, 0, 0, 1, 1], &[5.1, 5.2, 5.1, 6.9, 7.0], "White");
+    ///
+    /// let jitter = 50.0;
+    /// plot.set_points_jitter(jitter);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_points_jitter(&mut self, p: Percentage) {
+        assert!((0.0..=100.0).contains(&p), "Jitter must be between 0 and 100, got {p}.");
+        self.last_points().jitter = p;
+    }
+
+    fn last_points(&mut self) -> &mut Points<'a> {
+        self.points.last_mut().expect("Can not style points before adding them.")
+    }
+
+    // Make sure there is one error for every value in the last added category, and return its index.
+    fn assert_error_bar_count(&self, errors: &[f64]) -> usize {
+        assert!(!self.values.is_empty(), "Can not add error bars before adding values.");
+
+        let category = self.values.len() - 1;
+        let exp_count = self.values[category].len();
+        let count = errors.len();
+        assert_eq!(
+            exp_count,
+            count,
+            "Error bars should be same count as values, expected {exp_count}, got {count}"
+        );
+        category
+    }
+
+    /// Set a fill color as background.
+    ///
+    /// By default, the image will be fully transparent where there is nothing drawn on it.
+    /// Adding a background color might be a good idea for better visual presentation depending on usecase.
+    ///
+    /// # Accepted color conventions.
+    ///
+    /// * As its name such as "Red".
+    /// * As an RGB value such as "rgb(29, 28, 27)".
+    /// * As a HEX value such as "#1111FA".
+    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[1., 2., 3.,]);
+    ///
+    /// plot.set_background_color("Black");
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_background_color(&mut self, color: &'a str) {
+        self.colors.background = Some(color);
+    }
+
+    /// Set color for the lines.
+    ///
+    /// By default, all lines are drawn with a `default` color.
+    /// You can `override` this by setting your own color.
+    ///
+    /// # Accepted color conventions.
+    ///
+    /// * As its name such as "Red".
+    /// * As an RGB value such as "rgb(29, 28, 27)".
+    /// * As a HEX value such as "#1111FA".
+    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[1., 2., 3.,]);
+    ///
+    /// plot.set_line_color("Yellow");
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_line_color(&mut self, color: &'a str) {
+        self.colors.line = color;
+    }
+
+    /// Set color for text/numbers.
+    ///
+    /// By default, all text are drawn with a `default` color.
+    /// You can `override` this by setting your own color.
+    ///
+    /// # Accepted color conventions.
+    ///
+    /// * As its name such as "Red".
+    /// * As an RGB value such as "rgb(29, 28, 27)".
+    /// * As a HEX value such as "#1111FA".
+    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[1., 2., 3.,]);
+    ///
+    /// plot.set_text_color("LightBlue");
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_text_color(&mut self, color: &'a str) {
+        self.colors.text = color;
+    }
+
+    /// Set color for x-ticks and y-ticks.
+    ///
+    /// By default, all ticks are drawn with a `default` color.
+    /// You can `override` this by setting your own color.
+    ///
+    /// # Accepted color conventions.
+    ///
+    /// * As its name such as "Red".
+    /// * As an RGB value such as "rgb(29, 28, 27)".
+    /// * As a HEX value such as "#1111FA".
+    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[1., 2., 3.,]);
+    ///
+    /// plot.set_tick_color("LightBlue");
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_tick_color(&mut self, color: &'a str) {
+        self.colors.tick = color;
+    }
+
+    /// Set color for error bars.
+    ///
+    /// By default, error bars are drawn with a `default` color.
+    /// You can `override` this by setting your own color.
+    ///
+    /// # Accepted color conventions.
+    ///
+    /// * As its name such as "Red".
+    /// * As an RGB value such as "rgb(29, 28, 27)".
+    /// * As a HEX value such as "#1111FA".
+    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[1., 2., 3.,]);
+    /// plot.add_error_bars(&[0.2, 0.5, 0.1]);
+    ///
+    /// plot.set_error_bar_color("White");
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_error_bar_color(&mut self, color: &'a str) {
+        self.colors.error_bars = color;
+    }
+
+    /// Set color for the cumulative percentage line of a Pareto chart.
+    ///
+    /// By default, the line is drawn with a `default` color.
+    /// You can `override` this by setting your own color.
+    ///
+    /// # Accepted color conventions.
+    ///
+    /// * As its name such as "Red".
+    /// * As an RGB value such as "rgb(29, 28, 27)".
+    /// * As a HEX value such as "#1111FA".
+    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[12., 48., 7., 23.]);
+    /// plot.set_bars_pareto();
+    ///
+    /// plot.set_pareto_line_color("Orange");
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_pareto_line_color(&mut self, color: &'a str) {
+        self.colors.pareto_line = color;
+    }
+
+    /// Set color for the target marks of a bullet chart.
+    ///
+    /// By default, the target marks are drawn with a `default` color.
+    /// You can `override` this by setting your own color.
+    ///
+    /// # Accepted color conventions.
+    ///
+    /// * As its name such as "Red".
+    /// * As an RGB value such as "rgb(29, 28, 27)".
+    /// * As a HEX value such as "#1111FA".
+    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[270., 22.]);
+    /// plot.set_bars_bullet();
+    /// plot.add_bullet_range(&[150., 10.], "rgb(90, 90, 90)");
+    /// plot.add_bullet_range(&[300., 30.], "rgb(150, 150, 150)");
+    /// plot.set_bullet_targets(&[250., 25.]);
+    ///
+    /// plot.set_bullet_target_color("Black");
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_bullet_target_color(&mut self, color: &'a str) {
+        self.colors.bullet_target = color;
+    }
+
+    /// Highlight the last bar of a sparkline with its own color.
+    ///
+    /// This makes the latest value stand out, e.g. today's number at the end of a trend.
+    /// If more than one set of values is added, the last bar of each set is highlighted.
+    ///
+    /// Note: this only applies to sparklines, see [`BarPlot::to_sparkline_svg`].
+    ///
+    /// # Accepted color conventions.
+    ///
+    /// * As its name such as "Red".
+    /// * As an RGB value such as "rgb(29, 28, 27)".
+    /// * As a HEX value such as "#1111FA".
+    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[4., 7., 5., 9., 6., 8.]);
+    ///
+    /// plot.set_sparkline_last_bar_color("Orange");
+    ///
+    /// let svg: String = plot.to_sparkline_svg(120, 24);
+    /// ```
+    pub fn set_sparkline_last_bar_color(&mut self, color: &'a str) {
+        self.colors.sparkline_last_bar = Some(color);
+    }
+
+    /// Mark the lowest and the highest value of a sparkline with a dot.
+    ///
+    /// The dots are placed at the end of the bars for the first set of values.
+    /// Some room is left around the bars so that the dots are not cut off at the edges.
+    ///
+    /// Note: this only applies to sparklines, see [`BarPlot::to_sparkline_svg`].
+    ///
+    /// # Accepted color conventions.
+    ///
+    /// * As its name such as "Red".
+    /// * As an RGB value such as "rgb(29, 28, 27)".
+    /// * As a HEX value such as "#1111FA".
+    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[4., 7., 5., 9., 6., 8.]);
+    ///
+    /// plot.set_sparkline_min_max_color("Red");
+    ///
+    /// let svg: String = plot.to_sparkline_svg(120, 24);
+    /// ```
+    pub fn set_sparkline_min_max_color(&mut self, color: &'a str) {
+        self.colors.sparkline_min_max = Some(color);
+    }
+
+    /// Set a single color for all bars.
+    ///
+    /// By default, all bars are drawn with a `default` color.
+    /// You can `override` this by setting a new uniform color value.
+    ///
+    /// # Accepted color conventions.
+    ///
+    /// * As its name such as "Red".
+    /// * As an RGB value such as "rgb(29, 28, 27)".
+    /// * As a HEX value such as "#1111FA".
+    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[1., 2., 3.,]);
+    ///
+    /// plot.set_bar_colors_by_uniform("Green");
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_bar_colors_by_uniform(&mut self, color: &'a str) {
+        self.colors.bars.layout = BarColorLayout::Uniform(color);
+    }
+
+    /// Set bar colors by threshold.
+    ///
+    /// By default, all bars are drawn with a `default` color.
+    /// You can `override` this by setting different colors for different thresholds.
+    /// The threshold is as follows: minumum value, less than average, greater than or equal to average and max.
+    ///
+    /// # Accepted color conventions.
+    ///
+    /// * As its name such as "Red".
+    /// * As an RGB value such as "rgb(29, 28, 27)".
+    /// * As a HEX value such as "#1111FA".
+    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    ///
+    /// # Avoid if having more than one set of values.
+    ///
+    /// When you add multiple sets of values e.g. calling [`BarPlot::add_values`] multiple times,
+    /// then you might want to use [`BarPlot::add_bar_colors_by_category`] instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[1., 2., 3.,]);
+    ///
+    /// // Each color represent how signifacant a value is.
+    /// let min = "Red"; // The lowest value will have its bar colored red.
+    /// let low = "Orange"; // Low values will have their bars be orange.
+    /// let high = "Yellow"; // High values will be yellow.
+    /// let max = "Green"; // Max value (tallest bar) will be green.
+    ///
+    /// plot.set_bar_colors_by_threshold(min, low, high, max);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_bar_colors_by_threshold(&mut self, min: &'a str, low: &'a str, high: &'a str, max: &'a str) {
+        self.colors.bars.layout = BarColorLayout::Threshold((min, low, high, max));
+    }
+
+    /// Set bar colors for a waterfall chart.
+    ///
+    /// By default, all bars are drawn with a `default` color.
+    /// You can `override` this by setting different colors for bars going up, bars going down and totals.
+    /// Legend (see [`BarPlot::set_legend`]) will use these three colors in the same order.
+    ///
+    /// # Accepted color conventions.
+    ///
+    /// * As its name such as "Red".
+    /// * As an RGB value such as "rgb(29, 28, 27)".
+    /// * As a HEX value such as "#1111FA".
+    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    ///
+    /// # Only for waterfall charts.
+    ///
+    /// Use together with [`BarPlot::set_bars_waterfall`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[120., -30., 45., 0.]);
+    /// plot.set_bars_waterfall();
+    /// plot.set_waterfall_totals(&[3]);
+    ///
+    /// let increase = "Green";
+    /// let decrease = "Red";
+    /// let total = "Blue";
+    /// plot.set_bar_colors_by_waterfall(increase, decrease, total);
+    ///
+    /// let categories = ["Increase", "Decrease", "Total"];
+    /// plot.set_legend(&categories);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_bar_colors_by_waterfall(&mut self, increase: &'a str, decrease: &'a str, total: &'a str) {
+        self.colors.bars.layout = BarColorLayout::Waterfall((increase, decrease, total));
+    }
+
+    /// Set bar colors for bars above and below the baseline.
+    ///
+    /// By default, bars are drawn with a `default` color, or two default colors if a baseline is set.
+    /// You can `override` this by setting one color for bars above the baseline and one for bars below it.
+    /// Bars that end right at the baseline count as above it.
+    /// Legend (see [`BarPlot::set_legend`]) will use these two colors in the same order.
+    ///
+    /// # Accepted color conventions.
+    ///
+    /// * As its name such as "Red".
+    /// * As an RGB value such as "rgb(29, 28, 27)".
+    /// * As a HEX value such as "#1111FA".
+    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    ///
+    /// # Use together with a baseline.
+    ///
+    /// Use together with [`BarPlot::set_baseline`] or [`BarPlot::set_negative_bars_go_down`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// let spent = [9200., 11800., 10400., 12900.];
+    /// plot.add_values(&spent);
+    /// plot.set_scale_range(0, 15000, 1000);
+    /// plot.set_baseline(10000.);
+    ///
+    /// let under_budget = "Green";
+    /// let over_budget = "Red";
+    /// plot.set_bar_colors_by_baseline(over_budget, under_budget);
+    ///
+    /// let categories = ["Over budget", "Under budget"];
+    /// plot.set_legend(&categories);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_bar_colors_by_baseline(&mut self, above: &'a str, below: &'a str) {
+        self.colors.bars.layout = BarColorLayout::Baseline((above, below));
+    }
+
+    /// Set bar colors for candlesticks that are rising and falling.
+    ///
+    /// By default, candlesticks are drawn with two default colors.
+    /// You can `override` this by setting one color for bins closing at or above their opening
+    /// and one for bins closing below it. The wicks are drawn in the same color as their body.
+    /// Legend (see [`BarPlot::set_legend`]) will use these two colors in the same order.
+    ///
+    /// # Accepted color conventions.
+    ///
+    /// * As its name such as "Red".
+    /// * As an RGB value such as "rgb(29, 28, 27)".
+    /// * As a HEX value such as "#1111FA".
+    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    ///
+    /// # Use together with candlesticks.
+    ///
+    /// Use together with [`BarPlot::add_candlesticks`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// let open = [101.2, 103.8, 102.9];
+    /// let high = [104.5, 105.1, 103.3];
+    /// let low = [100.3, 102.2, 98.7];
+    /// let close = [103.8, 102.9, 99.4];
+    ///
+    /// plot.add_candlesticks(&open, &high, &low, &close);
+    /// plot.set_scale_range(95, 110, 5);
+    ///
+    /// plot.set_bar_colors_by_candlestick("Green", "Red");
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_bar_colors_by_candlestick(&mut self, rising: &'a str, falling: &'a str) {
+        self.colors.bars.layout = BarColorLayout::Candlestick((rising, falling));
+    }
+
+    /// Add color to last added values.
+    ///
+    /// By default, all bars are drawn with a `default` color.
+    /// You can `override` this by setting different colors for different categories.
+    /// Keep in mind that this only make sens to use if you have called [`BarPlot::add_values`]
+    /// at least 2 times. The two datasets are treated as two distinct categories.
+    /// Note: see [`BarPlot::set_legend`] for displaying the names with their respective color.
+    ///
+    /// # How it operates
+    /// The category added first will have its bars colored first.
+    /// Then, any consecutive call will apply proportional to all consecutive calls to [`BarPlot::add_values`].
+    ///
+    /// In simple terms: if you call [`BarPlot::add_values`] 5 times, then it is required to call this method
+    /// `exactly` 5 times. Each time with its own particular color.
+    ///
+    /// # Accepted color conventions.
+    ///
+    /// * As its name such as "Red".
+    /// * As an RGB value such as "rgb(29, 28, 27)".
+    /// * As a HEX value such as "#1111FA".
+    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    ///
+    /// # Avoid if having only one set of values.
+    ///
+    /// If you only add one set of values e.g. calling [`BarPlot::add_values`] one time,
+    /// then it makes no sense using this method. Use any of the other ways to set colors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// let apples: Vec<f64> = vec![5., 16., 17., 8., 3.];
+    /// let oranges: Vec<f64> = vec![7., 6., 7., 16., 9.];
+    ///
+    /// // Add first set of values.
+    /// plot.add_values(&apples);
+    /// // Adding a second set of values.
+    /// plot.add_values(&oranges);
+    ///
+    /// // First call adds a color to the first category. Red bacuse apples are often red.
+    /// plot.add_bar_colors_by_category("Red");
+    /// // Second call adds a color to the second category. Orange because; oranges are orange. :)
+    /// plot.add_bar_colors_by_category("Orange");
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn add_bar_colors_by_category(&mut self, color: &'a str) {
+        if let BarColorLayout::Category(v) = &mut self.colors.bars.layout {
+            v.push(color);
+        } else {
+            self.colors.bars.layout = BarColorLayout::Category(vec![color]);
+        }
+    }
+
+    /// Add a set of colors for every bar in last added category.
+    ///
+    /// By default, all bars are drawn with a `default` color.
+    /// You can `override` this by adding an array of colors which have same length as the values.
+    ///
+    /// # How it operates
+    /// The category added first will have its bars colored first.
+    /// Then, any consecutive call will apply proportional to all consecutive calls to [`BarPlot::add_values`].
+    ///
+    /// In simple terms: if you call [`BarPlot::add_values`] 5 times, then it is required to call this method
+    /// `exactly` 5 times. Each time with its own particular set of colors.
+    ///
+    /// # Accepted color conventions.
+    ///
+    /// * As its name such as "Red".
+    /// * As an RGB value such as "rgb(29, 28, 27)".
+    /// * As a HEX value such as "#1111FA".
+    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    ///
+    /// This method is meant for users that want full control over the bar colors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// let apples: Vec<f64> = vec![5., 16., 17., 8., 3.];
+    /// let oranges: Vec<f64> = vec![7., 6., 7., 16., 9.];
+    ///
+    /// // Add first set of values.
+    /// plot.add_values(&apples);
+    /// // Adding a second set of values.
+    /// plot.add_values(&oranges);
+    ///
+    /// // First call adds a color to the first category. Red bacuse apples are often red.
+    /// let clr_red = vec!["Red", "Red", "Red", "Red", "Red"];
+    /// plot.add_bar_colors_from_vec(clr_red);
+    /// // Second call adds a color to the second category. Orange because; oranges are orange. :)
+    /// let clr_orange = vec!["Orange", "Orange", "Orange", "Orange", "Orange"];
+    /// plot.add_bar_colors_from_vec(clr_orange);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn add_bar_colors_from_vec(&mut self, colors: Vec<&'a str>) {
+        if let BarColorLayout::Indexed(v) = &mut self.colors.bars.layout {
+            v.push(colors);
+        } else {
+            self.colors.bars.layout = BarColorLayout::Indexed(vec![colors]);
+        }
+    }
+
+    /// Override any bar with any color.
+    ///
+    /// This one will override the category index and the value (bar) index with a color.
+    /// Say you added 3 sets of values by calling [`BarPlot::add_values`] 3 times.
+    /// This means you have 3 categories times `X` values where `X` is the length of array holding
+    /// each of set of values.
+    ///
+    /// # How it operates
+    /// The category added first will have its category and bars colored zero indexed.
+    /// The first bar in the first category will have index 0-0. The next bar will have 0-1.
     /// You call this method as many times as you need, passing one color at the time.
     ///
-    /// Calling [`BarPlot::add_values`] 5 times with 3 values for each set of values,
-    /// then the index for category will be 0-4 (5 indexes ) and the index for values will be 0-2 (3 indexes).
+    /// Calling [`BarPlot::add_values`] 5 times with 3 values for each set of values,
+    /// then the index for category will be 0-4 (5 indexes ) and the index for values will be 0-2 (3 indexes).
+    ///
+    /// # Accepted color conventions.
+    ///
+    /// * As its name such as "Red".
+    /// * As an RGB value such as "rgb(29, 28, 27)".
+    /// * As a HEX value such as "#1111FA".
+    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    ///
+    /// # Avoid if having only one set of values.
+    ///
+    /// If you only add one set of values e.g. calling [`BarPlot::add_values`] one time,
+    /// then it makes no sense using this method. Use any of the other ways to set colors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// let apples: Vec<f64> = vec![5., 16., 17., 8., 3.];
+    /// let oranges: Vec<f64> = vec![7., 6., 7., 16., 9.];
+    ///
+    /// // Add first set of values.
+    /// plot.add_values(&apples);
+    /// // Adding a second set of values.
+    /// plot.add_values(&oranges);
+    ///
+    /// // First call adds a color to the first category. Red bacuse apples are often red.
+    /// let clr_red = vec!["Red", "Red", "Red", "Red", "Red"];
+    /// plot.add_bar_colors_from_vec(clr_red);
+    /// // Second call adds a color to the second category. Orange because; oranges are orange. :)
+    /// let clr_orange = vec!["Orange", "Orange", "Orange", "Orange", "Orange"];
+    /// plot.add_bar_colors_from_vec(clr_orange);
+    ///
+    /// // Setting the first apple = green.
+    /// plot.add_bar_color_override(0, 0, "Green");
+    /// // Setting the last orange to blue.. :)
+    /// plot.add_bar_color_override(1, 4, "Blue");
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn add_bar_color_override(&mut self, category: usize, bar: usize, color: &'a str) {
+        assert!(
+            category < self.values.len(),
+            "Can not add category index '{category}' as there are not enough categories added yet.\n{}",
+            "This may result in current override being ignored.",
+        );
+
+        self.colors.bars.overrides.push((category, bar, color));
+    }
+
+    /// Show horizontal grid lines.
+    ///
+    /// # Important
+    ///
+    /// Call [`BarPlot::set_scale_range`] first, otherwise there are no values to base the grid on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[5.0, 16.4, 17.1, 13.7, 8.9, 3.9, 6.3, 9.6]);
+    ///
+    /// // Needed for horizontal (y-grid) lines.
+    /// plot.set_scale_range(0, 20, 2);
+    ///
+    /// plot.set_show_horizontal_lines();
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_show_horizontal_lines(&mut self) {
+        self.show.horizontal_lines = true;
+    }
+
+    /// Add custom horizontal grid lines.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// let values = [5.0, 16.4, 17.1, 13.7, 8.9, 3.9, 6.3, 9.6];
+    /// plot.add_values(&values);
+    ///
+    /// // Add a horizontal lines.
+    /// let line_color = "rgb(126, 255, 165)";
+    /// plot.add_horizontal_line_at(25.0, line_color);
+    /// plot.add_horizontal_line_at(50.0, line_color);
+    /// plot.add_horizontal_line_at(75.0, line_color);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn add_horizontal_line_at(&mut self, p: Percentage, color: &'a str) {
+        self.lines_at.push(LinesAt::Horizontal(p, color));
+    }
+
+    /// Show vertical grid lines.
+    ///
+    /// # Important
+    ///
+    ///  Call [`BarPlot::set_bin_markers`] first, otherwise there are no values to base the grid on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// let values = [5.0, 16.4, 17.1, 13.7, 8.9, 3.9, 6.3, 9.6];
+    /// plot.add_values(&values);
+    ///
+    /// // Needed for vertical (x-grid) lines.
+    /// let markers: Vec<String> = (0..values.len()).map(|i| (i).to_string()).collect();
+    /// let markers = markers.iter().map(|s| s.as_str()).collect::<Vec<&str>>();
+    /// plot.set_bin_markers(&markers);
+    ///
+    /// plot.set_show_vertical_lines();
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_show_vertical_lines(&mut self) {
+        self.show.vertical_lines = true;
+    }
+
+    /// Add custom vertical grid lines.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// let values = [5.0, 16.4, 17.1, 13.7, 8.9, 3.9, 6.3, 9.6];
+    /// plot.add_values(&values);
+    ///
+    /// // Add vertical lines.
+    /// let line_color = "rgb(126, 255, 165)";
+    /// plot.add_vertical_line_at(25.0, line_color);
+    /// plot.add_vertical_line_at(50.0, line_color);
+    /// plot.add_vertical_line_at(75.0, line_color);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn add_vertical_line_at(&mut self, p: Percentage, color: &'a str) {
+
+        self.lines_at.push(LinesAt::Vertical(p, color));
+    }
+
+    /// Add a line across the plot at a value on the scale.
+    ///
+    /// Unlike [`BarPlot::add_horizontal_line_at`] and [`BarPlot::add_vertical_line_at`], the line is placed
+    /// by value instead of by percentage, so it follows the scale and the orientation of the bars.
+    /// On a timeline (see [`BarPlot::add_timeline`]), this is how you would mark "now".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[5.0, 16.4, 17.1, 13.7]);
+    /// plot.set_scale_range(0, 20, 5);
+    ///
+    /// // A line marking the goal.
+    /// plot.add_scale_line_at(15.0, "Red");
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn add_scale_line_at(&mut self, value: f64, color: &'a str) {
+        self.lines_at.push(LinesAt::Value(value, color));
+    }
+
+    /// Set size of the barplot size (relative to the canvas/frame).
+    ///
+    /// By default, the barchart part of the image will take up the full width and length of the frame.
+    /// However, by adding literally anything to the barplot, you most likely want to change the size of the plot.
+    /// If you skip this method, the text, ticks, markers, legend etc. will not be inside the viewbox of the canvas.
+    ///
+    /// # How it operates
+    ///
+    /// We only work with percentage values when defining sizes. A width of 100 means it takes up 100% of the width.
+    /// A height of 100 will do the same, but for height.
+    /// Same goes for offset. A horizontal offset of 10 means that the offset is pushed 10% from the left.
+    /// A vertical offset of 10 means that the offset is pushed 10% from the top.
+    /// An offset with value 100 means the offset is pushed all the way to the right (horizontal) or bottom (vertical).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[1., 2., 3.,]);
+    ///
+    /// let width = 90.0; // Set width to 90%,
+    /// let horizontal_offset = 65.0; // Move 65% right
+    /// let height = 85.0; // Set height to 85%
+    /// let vertical_offset = 40.0; // Move 40% down.
+    /// plot.set_plot_window_size(width, horizontal_offset, height, vertical_offset);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_plot_window_size(
+        &mut self,
+        x_length: Percentage,
+        x_offset: Percentage,
+        y_length: Percentage,
+        y_offset: Percentage
+    ) {
+        assert!(x_length <= 100.0 && x_offset <= 100.0, "plot window width cannot exceed 100%");
+        assert!(y_length <= 100.0 && y_offset <= 100.0, "plot window height cannot exceed 100%");
+
+        self.layout.plot_window_scale = Some((x_length, x_offset, y_length, y_offset));
+    }
+
+    /// Set a scale for the barchart.
+    ///
+    /// By default, the scale is calculated using the maximum and minimum value throughout all data.
+    /// However, by setting the scale manually we get a more presentable plot.
+    /// Pass whole numbers (can be negative) as the scale minimum and scale maximum.
+    /// The 3rd parameter is the gap between each number on the scale e.g. the step.
+    /// The step must be a positive number as it is an increasing number starting from minimum.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[5.0, 16.4, 17.1, 13.7, 8.9, 3.9, 6.3, 9.6]);
+    ///
+    /// let min = 0;
+    /// let max = 20;
+    /// let step = 2;
+    ///
+    /// plot.set_scale_range(min, max, step);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_scale_range(&mut self, min: i64, max: i64, step: u64) {
+        self.layout.scale_range = Some((min as f64, max as f64, step as f64));
+        self.layout.scale_range_auto = false;
+    }
+
+    /// Derive a scale for the barchart from the values.
+    ///
+    /// Works like [`BarPlot::set_scale_range`], except that the minimum, maximum and step are picked for you.
+    /// The scale always includes zero and covers all values, and the step is 1, 2, 2.5 or 5 times a power of ten.
+    /// Use this when the values are not known up front, or when they are fractions
+    /// (like a running fraction from [`crate::Histogram::set_fraction`]) which can not be passed as whole numbers.
+    ///
+    /// Calling [`BarPlot::set_scale_range`] afterwards will override this and vice versa.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[0.12, 0.48, 0.31, 0.09]);
+    ///
+    /// // Gives a scale from 0 to 0.5 with a step of 0.05.
+    /// plot.set_scale_range_auto();
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_scale_range_auto(&mut self) {
+        self.layout.scale_range = None;
+        self.layout.scale_range_auto = true;
+    }
+
+    /// Set the labels and markers for each bin/bucket on the x-axis.
+    ///
+    /// Note: Passing an array with fewer bin markers than added values will cause some bins to be un-labeled.
+    /// To make sure everything is correct, it is recommended to pass the same amount of markers as values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// let absence_boys = [5., 3., 8., 4., 7.];
+    /// let absence_girls = [4., 1., 2., 3., 1.];
+    /// let weekdays = ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday",];
+    ///
+    /// plot.add_values(&absence_boys);
+    /// plot.add_values(&absence_girls);
+    /// plot.set_bin_markers(&weekdays);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_bin_markers(&mut self, markers: &'a [&'a str]) {
+        self.markers = Some(markers.to_vec());
+    }
+
+    /// Place the bin markers at the middle of each bin/bucket instead of to the left.
+    ///
+    /// By default, the bin markers are placed on the left side in between each bin.
+    /// You can have the markers placed either `left`, `middle` or `right` depending on usecase.
+    ///
+    /// Note: check out [`BarPlot::set_bin_markers_left`] and [`BarPlot::set_bin_markers_right`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// let values = [1., 2., 3.];
+    /// plot.add_values(&values);
+    ///
+    /// let markers: Vec<String> = (0..values.len()).map(|i| (i).to_string()).collect();
+    /// let markers = markers.iter().map(|s| s.as_str()).collect::<Vec<&str>>();
+    /// plot.set_bin_markers(&markers);
+    ///
+    /// plot.set_bin_markers_middle();
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_bin_markers_middle(&mut self) {
+        self.layout.bin_marker_position = BinMarkerPosition::Middle;
+    }
+
+    /// Place the bin markers to the left of each bin/bucket.
+    ///
+    /// By default, the bin markers are already placed on the left side in between each bin.
+    /// This method can be used to _reset_ an eventual change.
+    /// You can have the markers placed either `left`, `middle` or `right` depending on usecase.
+    ///
+    /// Note: check out [`BarPlot::set_bin_markers_middle`] and [`BarPlot::set_bin_markers_right`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// let values = [1., 2., 3.];
+    /// plot.add_values(&values);
+    ///
+    /// let markers: Vec<String> = (0..values.len()).map(|i| (i).to_string()).collect();
+    /// let markers = markers.iter().map(|s| s.as_str()).collect::<Vec<&str>>();
+    /// plot.set_bin_markers(&markers);
+    ///
+    /// // Setting markers at middle.
+    /// plot.set_bin_markers_middle();
+    /// // Then back to left side.
+    /// plot.set_bin_markers_left();
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_bin_markers_left(&mut self) {
+        self.layout.bin_marker_position = BinMarkerPosition::Left;
+    }
+
+    /// Place the bin markers to the right of each bin/bucket instead of to the left.
+    ///
+    /// By default, the bin markers are placed on the left side in between each bin.
+    /// You can have the markers placed either `left`, `middle` or `right` depending on usecase.
+    ///
+    /// Note: check out [`BarPlot::set_bin_markers_left`] and [`BarPlot::set_bin_markers_middle`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// let values = [1., 2., 3.];
+    /// plot.add_values(&values);
+    ///
+    /// let markers: Vec<String> = (0..values.len()).map(|i| (i).to_string()).collect();
+    /// let markers = markers.iter().map(|s| s.as_str()).collect::<Vec<&str>>();
+    /// plot.set_bin_markers(&markers);
+    ///
+    /// plot.set_bin_markers_right();
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_bin_markers_right(&mut self) {
+        self.layout.bin_marker_position = BinMarkerPosition::Right;
+    }
+
+    /// Give each bin a width proportional to its interval, by setting where the bins start and end.
+    ///
+    /// By default, all bins have the same width. With bin edges, the bin axis becomes numeric and each bin spans
+    /// from one edge to the next. This is needed for e.g. histograms of income brackets, where the brackets
+    /// are of different size. Bins added with [`BarPlot::add_histogram`] get their edges set automatically.
+    ///
+    /// # How it operates
+    ///
+    /// There must be one more edge than there are bins, as the last edge is where the last bin ends.
+    /// The edges must be increasing.
+    ///
+    /// If no bin markers are set with [`BarPlot::set_bin_markers`], the edges are used as bin markers,
+    /// placed where each bin starts (and where the last one ends).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// // Share of households per 1000 of income, in brackets of different size.
+    /// let households = [0.9, 2.1, 1.4, 0.6, 0.1];
+    /// let income = [0., 200., 400., 600., 1000., 2000.];
+    ///
+    /// plot.add_values(&households);
+    /// plot.set_bin_edges(&income);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_bin_edges(&mut self, edges: &[f64]) {
+        assert!(edges.len() >= 2, "There must be at least two bin edges, got {}.", edges.len());
+        assert!(edges.windows(2).all(|pair| pair[0] < pair[1]), "Bin edges must be increasing.");
+        self.layout.bin_edges = Some(edges.to_vec());
+    }
+
+    /// Give each bin its own width, relative to the other bins.
+    ///
+    /// Works like [`BarPlot::set_bin_edges`], but the bins are given by their width, starting from zero.
+    /// This lets the width encode a second variable, such as in a Marimekko chart
+    /// where the width of each bin is the size of a market.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// let markets = ["Nordics", "Germany", "France"];
+    /// let market_share = [34., 21., 17.];
+    /// let market_size = [27., 84., 68.];
+    ///
+    /// plot.add_values(&market_share);
+    /// plot.set_bin_markers(&markets);
+    /// plot.set_bin_widths(&market_size);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_bin_widths(&mut self, widths: &[f64]) {
+        assert!(widths.iter().all(|width| *width > 0.0), "Bin widths must be larger than zero.");
+        let mut edges = Vec::with_capacity(widths.len() + 1);
+        edges.push(0.0);
+        for width in widths {
+            edges.push(edges[edges.len() - 1] + width);
+        }
+        self.set_bin_edges(&edges);
+    }
+
+    /// Introduce a `gap` between every bar.
+    ///
+    /// The gap is calculated using a percentage.
+    /// A gap of 0 means there is no gap/air between bars.
+    /// A gap of 50 means that the bar and the gap will take up the same width.
+    /// A gap of 100 means that the gap will take up all space and so the bar becomes invisible.
+    ///
+    /// Note: check out [`BarPlot::set_bin_gap`] for gap only betweem bins.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[1., 2., 3.]);
+    /// plot.add_values(&[4., 5., 6.]);
+    ///
+    /// let gap = 30.0; // The gap will take up 30% of the space, leaving 70% for bar.
+    /// plot.set_bar_gap(gap);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_bar_gap(&mut self, gap: Percentage) {
+        self.layout.bar_gap = gap;
+    }
+
+    /// Introduce a `gap` between every bin/bucket.
+    ///
+    /// The gap is calculated using a percentage.
+    /// A gap of 0 means there is no gap/air between bins.
+    /// A gap of 50 means that the bin and the gap will take up the same width.
+    /// A gap of 100 means that the gap will take up all space and so the bin squashed into nothing.
+    ///
+    /// Note: check out [`BarPlot::set_bar_gap`] for gap betweem bars.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[1., 2., 3.]);
+    /// plot.add_values(&[4., 5., 6.]);
+    ///
+    /// let gap = 30.0; // The gap will take up 30% of the space, leaving 70% for bin.
+    /// plot.set_bin_gap(gap);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_bin_gap(&mut self, gap: Percentage) {
+        self.layout.bin_gap = gap;
+    }
+
+    /// Set length for ticks on the y axis.
+    ///
+    /// The length is calculated using a percentage.
+    /// A length of 0 means the tick will not be generated.
+    /// A length of 10 means that the tick will be of a _somewhat_ _normal_ length.
+    /// A length of greater than 10 means that the tick will be long.
+    /// Try different lengths to find the best length for your usecase.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[1., 2., 3.]);
+    ///
+    /// let len = 20.0; // The tick length will be of considerate length.
+    /// plot.set_y_axis_tick_length(len);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_y_axis_tick_length(&mut self, p: Percentage) {
+        self.layout.y_axis_tick_length = p;
+    }
+
+    /// Set length for ticks on the x axis.
+    ///
+    /// The length is calculated using a percentage.
+    /// A length of 0 means the tick will not be generated.
+    /// A length of 10 means that the tick will be of a _somewhat_ _normal_ length.
+    /// A length of greater than 10 means that the tick will be long.
+    /// Try different lengths to find the best length for your usecase.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[1., 2., 3.]);
+    ///
+    /// let len = 20.0; // The tick length will be of considerate length.
+    /// plot.set_x_axis_tick_length(len);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_x_axis_tick_length(&mut self, p: Percentage) {
+        self.layout.x_axis_tick_length = p;
+    }
+
+    /// Anchor bars at zero instead of the floor.
+    /// This will make negative bars grow downwards.
+    /// If your dataset contains only negative values, then this might not make sense to use.
+    /// Rather, `use this when your dataset is likely to contain both positive and negative values`.
+    ///
+    /// An area where it makes sens is when visualizing temperature differences. :)
+    ///
+    /// By default, bars are anchored at the floor of the barchart.
+    /// However, you might want negative values to stand out by having them point downwards.
+    /// This method will apply anchoring bars at the zero line instead of the floor.
+    ///
+    /// # Important
+    /// Call [`BarPlot::set_scale_range`] first, and make sure to set `min < 0` and `max >= 0`.
+    /// Otherwise, you ~might~ will get a barchart that looks goofy. Consider yourself warned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[5.0, -16.4, 17.1, 13.7, 8.9, 3.9, -6.3, 9.6]);
+    ///
+    /// // Adding some extra tweaks that makes this example more clear.
+    /// let min = -20;
+    /// let max = 20;
+    /// let step = 2;
+    /// plot.set_scale_range(min, max, step);
+    /// plot.set_plot_window_size(90.0, 80.0, 83.0, 50.0);
+    ///
+    /// // Negative bars will now grow downwards instead of upwards. :)
+    /// plot.set_negative_bars_go_down();
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_negative_bars_go_down(&mut self) {
+        self.layout.negative_bars_go_down = true;
+    }
+
+    /// Make bars grow up or down from a baseline value instead of from the bottom of the scale.
+    ///
+    /// Bars with a value above the baseline grow upwards from it, while bars below it grow downwards.
+    /// Use this for showing values against e.g. a budget target or a comfortable room temperature.
+    /// This works like [`BarPlot::set_negative_bars_go_down`], but for any value and not only zero.
+    ///
+    /// # How it operates
+    ///
+    /// The baseline is drawn as a line across the plot, using the color from [`BarPlot::set_line_color`].
+    /// If no bar colors are set, bars above and below the baseline are given separate default colors.
+    /// Check out [`BarPlot::set_bar_colors_by_baseline`] for choosing these colors yourself.
+    ///
+    /// Stacked bars always grow out from zero, and floating bars (see [`BarPlot::add_ranges`]) have their own start,
+    /// so the baseline has no effect on where these bars start.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// let indoor_temperature = [18.5, 19.2, 21.4, 22.8, 20.1, 17.9];
+    /// plot.add_values(&indoor_temperature);
+    ///
+    /// plot.set_scale_range(15, 25, 1);
+    ///
+    /// // Bars will now grow up or down from 20°C.
+    /// plot.set_baseline(20.0);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_baseline(&mut self, value: f64) {
+        self.layout.baseline = Some(value);
+        if let BarColorLayout::Uniform(DEFAULT_BAR_COLOR) = self.colors.bars.layout {
+            self.colors.bars.layout = BarColorLayout::Baseline((DEFAULT_BAR_COLOR, DEFAULT_BAR_COLOR_BELOW));
+        }
+    }
+
+    /// Draw bars horizontally instead of vertically.
+    ///
+    /// By default, bins are laid out from left to right and bars grow upwards.
+    /// With horizontal orientation, bins are stacked from top to bottom and bars grow from left to right.
+    /// This comes in handy when bin markers are long, such as product names or file paths.
+    ///
+    /// # How it operates
+    ///
+    /// Everything that belongs to the bins and the values swap axis.
+    /// The scale from [`BarPlot::set_scale_range`] is drawn along the x-axis (bottom of the plot window),
+    /// while the bin markers from [`BarPlot::set_bin_markers`] are drawn along the y-axis (left of the plot window).
+    /// Ticks follow their axis, meaning [`BarPlot::set_x_axis_tick_length`] now applies to the scale
+    /// and [`BarPlot::set_y_axis_tick_length`] applies to the bin markers.
+    /// The same goes for grid lines, [`BarPlot::set_show_vertical_lines`] is now based on the scale
+    /// and [`BarPlot::set_show_horizontal_lines`] is based on the bin markers.
+    ///
+    /// Note: check out [`BarPlot::set_orientation_vertical`] for going back to the default.
     ///
-    /// # Accepted color conventions.
+    /// # Example
     ///
-    /// * As its name such as "Red".
-    /// * As an RGB value such as "rgb(29, 28, 27)".
-    /// * As a HEX value such as "#1111FA".
-    /// * As an HSL value such as "hsl(30, 3.80%, 10.20%)".
+    /// ```
+    /// use eb_bars::BarPlot;
     ///
-    /// # Avoid if having only one set of values.
+    /// let mut plot = BarPlot::new();
     ///
-    /// If you only add one set of values e.g. calling [`BarPlot::add_values`] one time,
-    /// then it makes no sense using this method. Use any of the other ways to set colors.
+    /// let downloads = [1204., 873., 455., 312.];
+    /// let packages = ["serde_json", "chrono", "rand", "eb_bars"];
+    ///
+    /// plot.add_values(&downloads);
+    /// plot.set_bin_markers(&packages);
+    /// plot.set_scale_range(0, 1400, 200);
+    ///
+    /// // Make room on the left side for the package names.
+    /// plot.set_plot_window_size(75.0, 90.0, 85.0, 30.0);
+    ///
+    /// plot.set_orientation_horizontal();
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_orientation_horizontal(&mut self) {
+        self.layout.orientation = BarOrientation::Horizontal;
+    }
+
+    /// Draw bars vertically.
+    ///
+    /// By default, bars are already drawn vertically e.g. bins are laid out from left to right
+    /// and bars grow upwards. This method can be used to _reset_ an eventual change.
+    ///
+    /// Note: check out [`BarPlot::set_orientation_horizontal`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[1., 2., 3.]);
+    ///
+    /// // Setting bars horizontal.
+    /// plot.set_orientation_horizontal();
+    /// // Then back to vertical.
+    /// plot.set_orientation_vertical();
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_orientation_vertical(&mut self) {
+        self.layout.orientation = BarOrientation::Vertical;
+    }
+
+    /// Draw bars around a circle, also known as a radial or polar bar chart.
+    ///
+    /// Bins are laid out clockwise around the center, starting from the top, and bars grow outwards from the center.
+    /// Cyclic data such as hours of the day or months of the year reads well this way,
+    /// as the last bin ends up right next to the first one.
+    ///
+    /// # How it operates
+    ///
+    /// The circle is the largest one fitting in the plot window (see [`BarPlot::set_plot_window_size`]),
+    /// and bin markers are placed just outside of it, so leave some room around the plot window.
+    /// The scale is drawn as circles around the center with [`BarPlot::set_show_horizontal_lines`],
+    /// labeled straight upwards. With [`BarPlot::set_show_vertical_lines`], lines go from the center to each bin marker.
+    /// Colors, legend and stacked bars work as usual.
+    ///
+    /// # Important
+    ///
+    /// Error bars, curves, line series, points, stack totals and waterfall connectors are not supported,
+    /// neither are lollipop or dumbbell styles, Pareto charts and population pyramids.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// let hours = ["00", "03", "06", "09", "12", "15", "18", "21"];
+    /// let visitors = [12., 4., 9., 48., 61., 57., 70., 35.];
+    ///
+    /// plot.add_values(&visitors);
+    /// plot.set_bin_markers(&hours);
+    /// plot.set_bin_markers_left();
+    ///
+    /// plot.set_orientation_radial();
+    /// plot.set_scale_range(0, 80, 20);
+    /// plot.set_show_horizontal_lines();
+    /// plot.set_plot_window_size(80.0, 50.0, 80.0, 50.0);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_orientation_radial(&mut self) {
+        self.layout.orientation = BarOrientation::Radial;
+    }
+
+    /// Rotate a radial chart clockwise by the given number of degrees.
+    ///
+    /// By default, the first bin starts straight up and bins follow clockwise.
+    /// Pass a negative value for rotating counter-clockwise, e.g. to center the first bin on the top.
+    ///
+    /// Note: this only applies to radial charts, see [`BarPlot::set_orientation_radial`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// let months = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+    /// let rainfall = [96., 71., 64., 48., 55., 61., 80., 95., 112., 126., 118., 104.];
+    ///
+    /// plot.add_values(&rainfall);
+    /// plot.set_bin_markers(&months);
+    /// plot.set_orientation_radial();
+    ///
+    /// // Center January on the top, instead of starting it there.
+    /// let degrees = -15.0;
+    /// plot.set_radial_rotation(degrees);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_radial_rotation(&mut self, degrees: f64) {
+        self.layout.radial_rotation = degrees;
+    }
+
+    /// Stack bars on top of each other instead of placing them side by side.
+    ///
+    /// By default, calling [`BarPlot::add_values`] multiple times will place the bars side by side in each bin.
+    /// With stacked bars, each category is drawn on top of the category added before it in the same bin.
+    /// This makes it easy to read both the total of each bin and how it splits between categories.
+    ///
+    /// # How it operates
+    ///
+    /// Stacks always grow out from zero. Positive values stack upwards while negative values stack
+    /// downwards below the zero line. Because of this, [`BarPlot::set_negative_bars_go_down`] has no effect.
+    ///
+    /// If no scale is set with [`BarPlot::set_scale_range`], the bars are scaled between the lowest
+    /// and highest point reached by any stack (zero included).
+    ///
+    /// Note: check out [`BarPlot::set_show_stack_totals`] for labeling each stack with its total,
+    /// [`BarPlot::set_stack_group`] for placing several stacks side by side in each bin
+    /// and [`BarPlot::set_bars_grouped`] for going back to the default.
     ///
     /// # Example
     ///
@@ -649,41 +2596,41 @@ impl <'a>BarPlot<'a> {
     /// let apples: Vec<f64> = vec![5., 16., 17., 8., 3.];
     /// let oranges: Vec<f64> = vec![7., 6., 7., 16., 9.];
     ///
-    /// // Add first set of values.
     /// plot.add_values(&apples);
-    /// // Adding a second set of values.
+    /// plot.add_bar_colors_by_category("Red");
     /// plot.add_values(&oranges);
+    /// plot.add_bar_colors_by_category("Orange");
     ///
-    /// // First call adds a color to the first category. Red bacuse apples are often red.
-    /// let clr_red = vec!["Red", "Red", "Red", "Red", "Red"];
-    /// plot.add_bar_colors_from_vec(clr_red);
-    /// // Second call adds a color to the second category. Orange because; oranges are orange. :)
-    /// let clr_orange = vec!["Orange", "Orange", "Orange", "Orange", "Orange"];
-    /// plot.add_bar_colors_from_vec(clr_orange);
+    /// plot.set_scale_range(0, 30, 5);
     ///
-    /// // Setting the first apple = green.
-    /// plot.add_bar_color_override(0, 0, "Green");
-    /// // Setting the last orange to blue.. :)
-    /// plot.add_bar_color_override(1, 4, "Blue");
+    /// // Oranges will now be placed on top of apples.
+    /// plot.set_bars_stacked();
     ///
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
-    pub fn add_bar_color_override(&mut self, category: usize, bar: usize, color: &'a str) {
-        assert!(
-            category < self.values.len(),
-            "{}\n{}",
-            format!("Can not add category index '{category}' as there are not enough categories added yet."),
-            "This may result in current override being ignored.",
-        );
-
-        self.colors.bars.overrides.push((category, bar, color));
+    pub fn set_bars_stacked(&mut self) {
+        self.layout.bar_mode = BarMode::Stacked;
     }
 
-    /// Show horizontal grid lines.
+    /// Stack bars on top of each other, scaled to their share of the bin total.
+    ///
+    /// Works like [`BarPlot::set_bars_stacked`], except that each value is replaced with its share
+    /// (in percent) of the total of its bin. Every stack will therefore reach the top of the plot window.
+    /// Use this when the split between categories matters more than the absolute numbers,
+    /// for example when totals vary wildly between bins.
+    ///
+    /// # How it operates
+    ///
+    /// The scale is always drawn from 0% to 100%, so there is no need to call [`BarPlot::set_scale_range`].
+    /// If you do call it, only the step is used, the minimum and maximum are ignored.
+    /// Bins where all values are zero are left empty.
+    ///
+    /// Colors and legend work the same way as for any other plot,
+    /// see [`BarPlot::add_bar_colors_by_category`] and [`BarPlot::set_legend`].
     ///
     /// # Important
     ///
-    /// Call [`BarPlot::set_scale_range`] first, otherwise there are no values to base the grid on.
+    /// All values must be zero or positive, as a negative share of a total does not make much sense.
     ///
     /// # Example
     ///
@@ -692,20 +2639,47 @@ impl <'a>BarPlot<'a> {
     ///
     /// let mut plot = BarPlot::new();
     ///
-    /// plot.add_values(&[5.0, 16.4, 17.1, 13.7, 8.9, 3.9, 6.3, 9.6]);
+    /// let desktop: Vec<f64> = vec![5310., 6102., 2004.];
+    /// let mobile: Vec<f64> = vec![7211., 9940., 1733.];
     ///
-    /// // Needed for horizontal (y-grid) lines.
-    /// plot.set_scale_range(0, 20, 2);
+    /// plot.add_values(&desktop);
+    /// plot.add_bar_colors_by_category("Red");
+    /// plot.add_values(&mobile);
+    /// plot.add_bar_colors_by_category("Orange");
     ///
-    /// plot.set_show_horizontal_lines();
+    /// let categories = ["Desktop", "Mobile"];
+    /// plot.set_legend(&categories);
+    ///
+    /// // Make room for the scale and the legend.
+    /// plot.set_plot_window_size(80.0, 30.0, 85.0, 40.0);
+    ///
+    /// plot.set_bars_stacked_percent();
     ///
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
-    pub fn set_show_horizontal_lines(&mut self) {
-        self.show.horizontal_lines = true;
+    pub fn set_bars_stacked_percent(&mut self) {
+        self.layout.bar_mode = BarMode::StackedPercent;
     }
 
-    /// Add custom horizontal grid lines.
+    /// Put the last added values into a stack group, so that several stacks are placed side by side in each bin.
+    ///
+    /// By default, all values go into stack group 0, which gives one stack in each bin.
+    /// Values in the same group are stacked on top of each other, while each group gets its own stack.
+    /// The stacks are placed side by side in order of their group, just like grouped bars.
+    /// This is how you would show e.g. revenue by product stacked, for this year and last year, next to each other.
+    ///
+    /// # How it operates
+    ///
+    /// Only has an effect with [`BarPlot::set_bars_stacked`] or [`BarPlot::set_bars_stacked_percent`].
+    /// With percentage stacks, each value is a share of the total of its own stack.
+    /// Stack totals from [`BarPlot::set_show_stack_totals`] are shown for each stack.
+    ///
+    /// Groups are numbered from zero and up, and a group without any values leaves an empty slot in the bin.
+    /// The space between the stacks is set with [`BarPlot::set_bar_gap`].
+    ///
+    /// # Important
+    ///
+    /// Values must be added with [`BarPlot::add_values`] before assigning them to a stack group.
     ///
     /// # Example
     ///
@@ -714,26 +2688,51 @@ impl <'a>BarPlot<'a> {
     ///
     /// let mut plot = BarPlot::new();
     ///
-    /// let values = [5.0, 16.4, 17.1, 13.7, 8.9, 3.9, 6.3, 9.6];
-    /// plot.add_values(&values);
+    /// let apples_2024: Vec<f64> = vec![5., 16., 17.];
+    /// let oranges_2024: Vec<f64> = vec![7., 6., 7.];
+    /// let apples_2025: Vec<f64> = vec![8., 14., 19.];
+    /// let oranges_2025: Vec<f64> = vec![6., 9., 5.];
     ///
-    /// // Add a horizontal lines.
-    /// let line_color = "rgb(126, 255, 165)";
-    /// plot.add_horizontal_line_at(25.0, line_color);
-    /// plot.add_horizontal_line_at(50.0, line_color);
-    /// plot.add_horizontal_line_at(75.0, line_color);
+    /// plot.add_values(&apples_2024);
+    /// plot.add_bar_colors_by_category("Red");
+    /// plot.add_values(&oranges_2024);
+    /// plot.add_bar_colors_by_category("Orange");
+    ///
+    /// // The values for 2025 are stacked next to the ones for 2024.
+    /// plot.add_values(&apples_2025);
+    /// plot.add_bar_colors_by_category("DarkRed");
+    /// plot.set_stack_group(1);
+    /// plot.add_values(&oranges_2025);
+    /// plot.add_bar_colors_by_category("DarkOrange");
+    /// plot.set_stack_group(1);
+    ///
+    /// plot.set_bars_stacked();
     ///
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
-    pub fn add_horizontal_line_at(&mut self, p: Percentage, color: &'a str) {
-        self.lines_at.push(LinesAt::Horizontal(p, color));
+    pub fn set_stack_group(&mut self, group: usize) {
+        let last = self.stack_groups.last_mut().expect("Can not assign a stack group before adding values.");
+        *last = group;
     }
 
-    /// Show vertical grid lines.
+    /// Draw a waterfall chart, where each value is a change from the previous bar.
     ///
-    /// # Important
+    /// Each bar floats from the running total of all bars before it, and ends at the new running total.
+    /// Positive values make the bar go up while negative values make it go down.
+    /// This is how you would show e.g. how revenue turns into profit, step by step.
     ///
-    ///  Call [`BarPlot::set_bin_markers`] first, otherwise there are no values to base the grid on.
+    /// # How it operates
+    ///
+    /// Only one set of values can be added with [`BarPlot::add_values`].
+    /// Bins that should show the running total itself (a subtotal or the final total) are set with
+    /// [`BarPlot::set_waterfall_totals`]. These bars grow from zero, and their value is ignored.
+    /// Pass any value for them, like zero.
+    ///
+    /// If no scale is set with [`BarPlot::set_scale_range`], bars are scaled between the lowest and highest
+    /// running total (zero included).
+    ///
+    /// Note: check out [`BarPlot::set_bar_colors_by_waterfall`] for coloring bars by going up, down or being a total
+    /// and [`BarPlot::set_show_waterfall_connectors`] for lines joining the bars.
     ///
     /// # Example
     ///
@@ -742,23 +2741,33 @@ impl <'a>BarPlot<'a> {
     ///
     /// let mut plot = BarPlot::new();
     ///
-    /// let values = [5.0, 16.4, 17.1, 13.7, 8.9, 3.9, 6.3, 9.6];
-    /// plot.add_values(&values);
+    /// let changes = [420., -180., -95., 0., 40., -60., 0.];
+    /// let labels = ["Revenue", "Cost of sales", "Salaries", "Gross profit", "Interest", "Tax", "Net profit"];
     ///
-    /// // Needed for vertical (x-grid) lines.
-    /// let markers: Vec<String> = (0..values.len()).map(|i| (i).to_string()).collect();
-    /// let markers = markers.iter().map(|s| s.as_str()).collect::<Vec<&str>>();
-    /// plot.set_bin_markers(&markers);
+    /// plot.add_values(&changes);
+    /// plot.set_bin_markers(&labels);
     ///
-    /// plot.set_show_vertical_lines();
+    /// plot.set_bars_waterfall();
+    /// // Gross profit and net profit are totals.
+    /// plot.set_waterfall_totals(&[3, 6]);
     ///
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
-    pub fn set_show_vertical_lines(&mut self) {
-        self.show.vertical_lines = true;
+    pub fn set_bars_waterfall(&mut self) {
+        self.layout.bar_mode = BarMode::Waterfall;
     }
 
-    /// Add custom vertical grid lines.
+    /// Set which bins of a waterfall chart hold a subtotal or total.
+    ///
+    /// The bins are zero indexed, e.g. passing `&[3]` makes the 4th bar a total.
+    /// A total bar shows the running total of all bars before it, growing from zero.
+    /// The running total carries on after it, as if it was never there.
+    ///
+    /// Note: this only applies to waterfall charts, see [`BarPlot::set_bars_waterfall`].
+    ///
+    /// # Important
+    ///
+    /// Every bin must be less than the number of bins, otherwise the plot will panic on render.
     ///
     /// # Example
     ///
@@ -767,35 +2776,33 @@ impl <'a>BarPlot<'a> {
     ///
     /// let mut plot = BarPlot::new();
     ///
-    /// let values = [5.0, 16.4, 17.1, 13.7, 8.9, 3.9, 6.3, 9.6];
-    /// plot.add_values(&values);
+    /// // Start with 100, add 20, remove 50 and then show the total.
+    /// plot.add_values(&[100., 20., -50., 0.]);
+    /// plot.set_bars_waterfall();
     ///
-    /// // Add vertical lines.
-    /// let line_color = "rgb(126, 255, 165)";
-    /// plot.add_vertical_line_at(25.0, line_color);
-    /// plot.add_vertical_line_at(50.0, line_color);
-    /// plot.add_vertical_line_at(75.0, line_color);
+    /// plot.set_waterfall_totals(&[3]);
     ///
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
-    pub fn add_vertical_line_at(&mut self, p: Percentage, color: &'a str) {
-
-        self.lines_at.push(LinesAt::Vertical(p, color));
+    pub fn set_waterfall_totals(&mut self, bins: &'a [usize]) {
+        self.waterfall_totals = Some(bins);
     }
 
-    /// Set size of the barplot size (relative to the canvas/frame).
+    /// Draw a Pareto chart, with the largest bins first and a line showing their cumulative share.
     ///
-    /// By default, the barchart part of the image will take up the full width and length of the frame.
-    /// However, by adding literally anything to the barplot, you most likely want to change the size of the plot.
-    /// If you skip this method, the text, ticks, markers, legend etc. will not be inside the viewbox of the canvas.
+    /// Bins are sorted in descending order and drawn like regular bars.
+    /// On top, a line shows the running total of the bars as a percentage of the sum of all bars.
+    /// The percentage is read from a secondary axis going from 0 to 100% on the right side of the plot
+    /// (or the top, if bars are horizontal). This makes it easy to spot the few bins making up most of the total.
     ///
     /// # How it operates
     ///
-    /// We only work with percentage values when defining sizes. A width of 100 means it takes up 100% of the width.
-    /// A height of 100 will do the same, but for height.
-    /// Same goes for offset. A horizontal offset of 10 means that the offset is pushed 10% from the left.
-    /// A vertical offset of 10 means that the offset is pushed 10% from the top.
-    /// An offset with value 100 means the offset is pushed all the way to the right (horizontal) or bottom (vertical).
+    /// Only one set of values can be added with [`BarPlot::add_values`], and all values must be zero or positive.
+    /// Bin markers set with [`BarPlot::set_bin_markers`] and colors set per bar, such as
+    /// [`BarPlot::add_bar_color_override`], are sorted along with their bins.
+    /// The secondary axis uses the same tick length and colors as the regular scale.
+    ///
+    /// Note: check out [`BarPlot::set_pareto_line_color`] for setting the color of the line.
     ///
     /// # Example
     ///
@@ -804,36 +2811,75 @@ impl <'a>BarPlot<'a> {
     ///
     /// let mut plot = BarPlot::new();
     ///
-    /// plot.add_values(&[1., 2., 3.,]);
+    /// let error_codes = ["E01", "E02", "E03", "E04", "E05"];
+    /// let occurrences = [14., 97., 3., 41., 22.];
     ///
-    /// let width = 90.0; // Set width to 90%,
-    /// let horizontal_offset = 65.0; // Move 65% right
-    /// let height = 85.0; // Set height to 85%
-    /// let vertical_offset = 40.0; // Move 40% down.
-    /// plot.set_plot_window_size(width, horizontal_offset, height, vertical_offset);
+    /// plot.add_values(&occurrences);
+    /// plot.set_bin_markers(&error_codes);
+    ///
+    /// plot.set_bars_pareto();
     ///
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
-    pub fn set_plot_window_size(
-        &mut self,
-        x_length: Percentage,
-        x_offset: Percentage,
-        y_length: Percentage,
-        y_offset: Percentage
-    ) {
-        assert!(x_length <= 100.0 && x_offset <= 100.0, "plot window width cannot exceed 100%");
-        assert!(y_length <= 100.0 && y_offset <= 100.0, "plot window height cannot exceed 100%");
+    pub fn set_bars_pareto(&mut self) {
+        self.layout.bar_mode = BarMode::Pareto;
+    }
 
-        self.layout.plot_window_scale = Some((x_length, x_offset, y_length, y_offset));
+    /// Draw a population pyramid, where two sets of values grow in opposite directions from the middle.
+    ///
+    /// Bars are drawn horizontally, with the first set of values growing to the left and the second to the right.
+    /// Both sides share the same scale, mirrored around the middle. This is useful for comparing two groups
+    /// bin by bin, such as men and women in each age group, or variant A and B of an experiment.
+    ///
+    /// # How it operates
+    ///
+    /// Exactly two sets of values must be added with [`BarPlot::add_values`], and all values must be zero or positive.
+    /// Colors and legend work as usual, see [`BarPlot::add_bar_colors_by_category`] and [`BarPlot::set_legend`].
+    ///
+    /// The scale set with [`BarPlot::set_scale_range`] applies to each side, so pass zero as the minimum.
+    /// If no scale is set, one is found from the values.
+    ///
+    /// Bin markers are placed on the left side by default.
+    /// Note: check out [`BarPlot::set_pyramid_markers_middle`] for placing them between the two sides.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// let age_groups = ["0-19", "20-39", "40-59", "60-79", "80+"];
+    /// let men = [12.4, 13.9, 13.1, 9.2, 2.1];
+    /// let women = [11.8, 13.2, 12.7, 10.1, 3.4];
+    ///
+    /// plot.add_values(&men);
+    /// plot.add_bar_colors_by_category("LightBlue");
+    /// plot.add_values(&women);
+    /// plot.add_bar_colors_by_category("Pink");
+    ///
+    /// plot.set_bin_markers(&age_groups);
+    /// plot.set_legend(&["Men", "Women"]);
+    ///
+    /// plot.set_bars_pyramid();
+    /// plot.set_scale_range(0, 15, 5);
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_bars_pyramid(&mut self) {
+        self.layout.bar_mode = BarMode::Pyramid;
+        self.layout.orientation = BarOrientation::Horizontal;
     }
 
-    /// Set a scale for the barchart.
+    /// Place the bin markers of a population pyramid in the middle, between the two sides.
     ///
-    /// By default, the scale is calculated using the maximum and minimum value throughout all data.
-    /// However, by setting the scale manually we get a more presentable plot.
-    /// Pass whole numbers (can be negative) as the scale minimum and scale maximum.
-    /// The 3rd parameter is the gap between each number on the scale e.g. the step.
-    /// The step must be a positive number as it is an increasing number starting from minimum.
+    /// The two sides move apart to make room for the markers. The room is calculated using a percentage
+    /// of the plot width, e.g. 10 leaves a tenth of the plot width between the two sides.
+    ///
+    /// # Important
+    ///
+    /// Only applies to population pyramids, see [`BarPlot::set_bars_pyramid`].
+    /// The plot will panic on render if bars are drawn in any other mode.
     ///
     /// # Example
     ///
@@ -842,52 +2888,82 @@ impl <'a>BarPlot<'a> {
     ///
     /// let mut plot = BarPlot::new();
     ///
-    /// plot.add_values(&[5.0, 16.4, 17.1, 13.7, 8.9, 3.9, 6.3, 9.6]);
+    /// plot.add_values(&[48., 35., 12.]);
+    /// plot.add_values(&[44., 39., 17.]);
+    /// plot.set_bin_markers(&["Desktop", "Mobile", "Tablet"]);
     ///
-    /// let min = 0;
-    /// let max = 20;
-    /// let step = 2;
+    /// plot.set_bars_pyramid();
     ///
-    /// plot.set_scale_range(min, max, step);
+    /// let gap = 12.0;
+    /// plot.set_pyramid_markers_middle(gap);
     ///
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
-    pub fn set_scale_range(&mut self, min: i64, max: i64, step: u64) {
-        self.layout.scale_range = Some((min, max, step as usize));
+    pub fn set_pyramid_markers_middle(&mut self, gap: Percentage) {
+        assert!((0.0..100.0).contains(&gap), "Gap must be at least 0 and less than 100, got {gap}.");
+        self.layout.pyramid_gap = Some(gap);
     }
 
-    /// Set the labels and markers for each bin/bucket on the x-axis.
+    /// Draw a bullet chart, where each bar is drawn in front of shaded ranges, with a target mark across it.
     ///
-    /// Note: Passing an array with fewer bin markers than added values will cause some bins to be un-labeled.
-    /// To make sure everything is correct, it is recommended to pass the same amount of markers as values.
+    /// The bar is drawn narrower than the bin, in front of two to five wider ranges (e.g. poor, fair and good)
+    /// that show how the value should be read. A target mark can be drawn across each bar.
+    /// This is a compact replacement for gauges, showing e.g. revenue against a target for each department.
+    ///
+    /// # How it operates
+    ///
+    /// Only one set of values can be added with [`BarPlot::add_values`].
+    /// The ranges are added with [`BarPlot::add_bullet_range`] and the targets with [`BarPlot::set_bullet_targets`].
+    ///
+    /// If no scale is set with [`BarPlot::set_scale_range`], [`BarPlot::set_scale_range_auto`] is applied,
+    /// which makes room for the ranges and targets as well as the bars.
+    ///
+    /// Note: check out [`BarPlot::set_bullet_target_color`] for setting the color of the target marks.
     ///
     /// # Example
     ///
     /// ```
     /// use eb_bars::BarPlot;
     ///
-    /// let mut plot = BarPlot::new();
+    /// let mut plot = BarPlot::new();
+    ///
+    /// let kpis = ["Revenue", "Profit", "Orders"];
+    /// let actual = [270., 22., 180.];
+    ///
+    /// plot.add_values(&actual);
+    /// plot.set_bin_markers(&kpis);
+    ///
+    /// plot.set_bars_bullet();
+    ///
+    /// plot.add_bullet_range(&[150., 10., 100.], "rgb(90, 90, 90)");
+    /// plot.add_bullet_range(&[225., 20., 160.], "rgb(130, 130, 130)");
+    /// plot.add_bullet_range(&[300., 30., 250.], "rgb(170, 170, 170)");
     ///
-    /// let absence_boys = [5., 3., 8., 4., 7.];
-    /// let absence_girls = [4., 1., 2., 3., 1.];
-    /// let weekdays = ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday",];
+    /// plot.set_bullet_targets(&[250., 25., 200.]);
     ///
-    /// plot.add_values(&absence_boys);
-    /// plot.add_values(&absence_girls);
-    /// plot.set_bin_markers(&weekdays);
+    /// plot.set_scale_range(0, 300, 50);
     ///
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
-    pub fn set_bin_markers(&mut self, markers: &'a [&'a str]) {
-        self.markers = Some(markers);
+    pub fn set_bars_bullet(&mut self) {
+        self.layout.bar_mode = BarMode::Bullet;
+        if self.layout.scale_range.is_none() {
+            self.layout.scale_range_auto = true;
+        }
     }
 
-    /// Place the bin markers at the middle of each bin/bucket instead of to the left.
+    /// Add a qualitative range to a bullet chart, with one value for each bin.
     ///
-    /// By default, the bin markers are placed on the left side in between each bin.
-    /// You can have the markers placed either `left`, `middle` or `right` depending on usecase.
+    /// Each value is where the range ends in that bin, and the range starts where the previously added range ended.
+    /// The first range starts at the bottom of the scale. Add the ranges from the lowest (e.g. poor) to the highest
+    /// (e.g. good), two to five of them. The ranges are not part of the legend.
     ///
-    /// Note: check out [`BarPlot::set_bin_markers_left`] and [`BarPlot::set_bin_markers_right`].
+    /// Ranges are usually shades of the same color, getting lighter (or darker) for each range.
+    ///
+    /// # Important
+    ///
+    /// Only applies to bullet charts, see [`BarPlot::set_bars_bullet`].
+    /// The plot will panic on render if bars are drawn in any other mode.
     ///
     /// # Example
     ///
@@ -896,28 +2972,28 @@ impl <'a>BarPlot<'a> {
     ///
     /// let mut plot = BarPlot::new();
     ///
-    /// let values = [1., 2., 3.];
-    /// plot.add_values(&values);
-    ///
-    /// let markers: Vec<String> = (0..values.len()).map(|i| (i).to_string()).collect();
-    /// let markers = markers.iter().map(|s| s.as_str()).collect::<Vec<&str>>();
-    /// plot.set_bin_markers(&markers);
+    /// plot.add_values(&[78., 64.]);
+    /// plot.set_bars_bullet();
     ///
-    /// plot.set_bin_markers_middle();
+    /// // Poor up to 50, fair up to 75 and good up to 100, for both bins.
+    /// plot.add_bullet_range(&[50., 50.], "rgb(90, 90, 90)");
+    /// plot.add_bullet_range(&[75., 75.], "rgb(130, 130, 130)");
+    /// plot.add_bullet_range(&[100., 100.], "rgb(170, 170, 170)");
     ///
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
-    pub fn set_bin_markers_middle(&mut self) {
-        self.layout.bin_marker_position = BinMarkerPosition::Middle;
+    pub fn add_bullet_range(&mut self, values: &'a [f64], color: &'a str) {
+        self.bullet_ranges.push((values, color));
     }
 
-    /// Place the bin markers to the left of each bin/bucket.
+    /// Set the targets of a bullet chart, drawn as a mark across the bar in each bin.
     ///
-    /// By default, the bin markers are already placed on the left side in between each bin.
-    /// This method can be used to _reset_ an eventual change.
-    /// You can have the markers placed either `left`, `middle` or `right` depending on usecase.
+    /// Pass one target for each bin, on the same scale as the bars.
     ///
-    /// Note: check out [`BarPlot::set_bin_markers_middle`] and [`BarPlot::set_bin_markers_right`].
+    /// # Important
+    ///
+    /// Only applies to bullet charts, see [`BarPlot::set_bars_bullet`].
+    /// The plot will panic on render if bars are drawn in any other mode.
     ///
     /// # Example
     ///
@@ -926,30 +3002,25 @@ impl <'a>BarPlot<'a> {
     ///
     /// let mut plot = BarPlot::new();
     ///
-    /// let values = [1., 2., 3.];
-    /// plot.add_values(&values);
-    ///
-    /// let markers: Vec<String> = (0..values.len()).map(|i| (i).to_string()).collect();
-    /// let markers = markers.iter().map(|s| s.as_str()).collect::<Vec<&str>>();
-    /// plot.set_bin_markers(&markers);
+    /// plot.add_values(&[78., 64.]);
+    /// plot.set_bars_bullet();
+    /// plot.add_bullet_range(&[50., 50.], "rgb(90, 90, 90)");
+    /// plot.add_bullet_range(&[100., 100.], "rgb(170, 170, 170)");
     ///
-    /// // Setting markers at middle.
-    /// plot.set_bin_markers_middle();
-    /// // Then back to left side.
-    /// plot.set_bin_markers_left();
+    /// plot.set_bullet_targets(&[80., 70.]);
     ///
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
-    pub fn set_bin_markers_left(&mut self) {
-        self.layout.bin_marker_position = BinMarkerPosition::Left;
+    pub fn set_bullet_targets(&mut self, targets: &'a [f64]) {
+        self.bullet_targets = Some(targets);
     }
 
-    /// Place the bin markers to the right of each bin/bucket instead of to the left.
+    /// Place bars side by side in each bin.
     ///
-    /// By default, the bin markers are placed on the left side in between each bin.
-    /// You can have the markers placed either `left`, `middle` or `right` depending on usecase.
+    /// By default, bars are already placed side by side (grouped) when calling [`BarPlot::add_values`]
+    /// multiple times. This method can be used to _reset_ an eventual change.
     ///
-    /// Note: check out [`BarPlot::set_bin_markers_left`] and [`BarPlot::set_bin_markers_middle`].
+    /// Note: check out [`BarPlot::set_bars_stacked`].
     ///
     /// # Example
     ///
@@ -958,29 +3029,24 @@ impl <'a>BarPlot<'a> {
     ///
     /// let mut plot = BarPlot::new();
     ///
-    /// let values = [1., 2., 3.];
-    /// plot.add_values(&values);
-    ///
-    /// let markers: Vec<String> = (0..values.len()).map(|i| (i).to_string()).collect();
-    /// let markers = markers.iter().map(|s| s.as_str()).collect::<Vec<&str>>();
-    /// plot.set_bin_markers(&markers);
+    /// plot.add_values(&[1., 2., 3.]);
+    /// plot.add_values(&[4., 5., 6.]);
     ///
-    /// plot.set_bin_markers_right();
+    /// // Stacking bars.
+    /// plot.set_bars_stacked();
+    /// // Then back to side by side.
+    /// plot.set_bars_grouped();
     ///
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
-    pub fn set_bin_markers_right(&mut self) {
-        self.layout.bin_marker_position = BinMarkerPosition::Right;
+    pub fn set_bars_grouped(&mut self) {
+        self.layout.bar_mode = BarMode::Grouped;
     }
 
-    /// Introduce a `gap` between every bar.
-    ///
-    /// The gap is calculated using a percentage.
-    /// A gap of 0 means there is no gap/air between bars.
-    /// A gap of 50 means that the bar and the gap will take up the same width.
-    /// A gap of 100 means that the gap will take up all space and so the bar becomes invisible.
+    /// Draw each bar as a thin stem with a dot at its value, also known as a lollipop chart.
     ///
-    /// Note: check out [`BarPlot::set_bin_gap`] for gap only betweem bins.
+    /// Everything else works as with regular bars: the scale, bin markers, colors and overrides.
+    /// With many bins, this looks a lot cleaner than a wall of bars.
     ///
     /// # Example
     ///
@@ -989,26 +3055,26 @@ impl <'a>BarPlot<'a> {
     ///
     /// let mut plot = BarPlot::new();
     ///
-    /// plot.add_values(&[1., 2., 3.]);
-    /// plot.add_values(&[4., 5., 6.]);
+    /// let downloads: Vec<f64> = (0..120).map(|day| 50.0 + (day % 7) as f64 * 10.0).collect();
+    /// plot.add_values(&downloads);
     ///
-    /// let gap = 30.0; // The gap will take up 30% of the space, leaving 70% for bar.
-    /// plot.set_bar_gap(gap);
+    /// plot.set_bar_style_lollipop();
     ///
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
-    pub fn set_bar_gap(&mut self, gap: Percentage) {
-        self.layout.bar_gap = gap;
+    pub fn set_bar_style_lollipop(&mut self) {
+        self.layout.bar_style = BarStyle::Lollipop;
     }
 
-    /// Introduce a `gap` between every bin/bucket.
+    /// Draw the two values of each bin as two dots joined by a line, also known as a dumbbell chart.
     ///
-    /// The gap is calculated using a percentage.
-    /// A gap of 0 means there is no gap/air between bins.
-    /// A gap of 50 means that the bin and the gap will take up the same width.
-    /// A gap of 100 means that the gap will take up all space and so the bin squashed into nothing.
+    /// This is a compact way of showing the change between two points in time, e.g. before and after.
     ///
-    /// Note: check out [`BarPlot::set_bar_gap`] for gap betweem bars.
+    /// # How it operates
+    ///
+    /// Exactly two sets of values must be added with [`BarPlot::add_values`], and bars must be grouped
+    /// (see [`BarPlot::set_bars_grouped`]). Each dot gets the color of its bar, so the legend works as usual.
+    /// The joining line uses the color from [`BarPlot::set_line_color`].
     ///
     /// # Example
     ///
@@ -1017,25 +3083,25 @@ impl <'a>BarPlot<'a> {
     ///
     /// let mut plot = BarPlot::new();
     ///
-    /// plot.add_values(&[1., 2., 3.]);
-    /// plot.add_values(&[4., 5., 6.]);
+    /// let before = [61., 48., 73.];
+    /// let after = [68., 45., 80.];
     ///
-    /// let gap = 30.0; // The gap will take up 30% of the space, leaving 70% for bin.
-    /// plot.set_bin_gap(gap);
+    /// plot.add_values(&before);
+    /// plot.add_bar_colors_by_category("Gray");
+    /// plot.add_values(&after);
+    /// plot.add_bar_colors_by_category("Orange");
+    ///
+    /// plot.set_bar_style_dumbbell();
     ///
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
-    pub fn set_bin_gap(&mut self, gap: Percentage) {
-        self.layout.bin_gap = gap;
+    pub fn set_bar_style_dumbbell(&mut self) {
+        self.layout.bar_style = BarStyle::Dumbbell;
     }
 
-    /// Set length for ticks on the y axis.
+    /// Draw each bar as a filled rectangle.
     ///
-    /// The length is calculated using a percentage.
-    /// A length of 0 means the tick will not be generated.
-    /// A length of 10 means that the tick will be of a _somewhat_ _normal_ length.
-    /// A length of greater than 10 means that the tick will be long.
-    /// Try different lengths to find the best length for your usecase.
+    /// This is the default, so there is no need to call this unless you want to be explicit.
     ///
     /// # Example
     ///
@@ -1044,24 +3110,21 @@ impl <'a>BarPlot<'a> {
     ///
     /// let mut plot = BarPlot::new();
     ///
-    /// plot.add_values(&[1., 2., 3.]);
+    /// plot.add_values(&[5., 16., 17., 8., 3.]);
     ///
-    /// let len = 20.0; // The tick length will be of considerate length.
-    /// plot.set_y_axis_tick_length(len);
+    /// plot.set_bar_style_rectangle();
     ///
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
-    pub fn set_y_axis_tick_length(&mut self, p: Percentage) {
-        self.layout.y_axis_tick_length = p;
+    pub fn set_bar_style_rectangle(&mut self) {
+        self.layout.bar_style = BarStyle::Rectangle;
     }
 
-    /// Set length for ticks on the x axis.
+    /// Set width of the caps at both ends of the error bars.
     ///
-    /// The length is calculated using a percentage.
-    /// A length of 0 means the tick will not be generated.
-    /// A length of 10 means that the tick will be of a _somewhat_ _normal_ length.
-    /// A length of greater than 10 means that the tick will be long.
-    /// Try different lengths to find the best length for your usecase.
+    /// The width is calculated using a percentage of the bar width.
+    /// A width of 0 means no caps, while a width of 100 means the caps are as wide as the bar.
+    /// By default, the caps are half the width of the bar.
     ///
     /// # Example
     ///
@@ -1070,31 +3133,23 @@ impl <'a>BarPlot<'a> {
     ///
     /// let mut plot = BarPlot::new();
     ///
-    /// plot.add_values(&[1., 2., 3.]);
+    /// plot.add_values(&[1., 2., 3.,]);
+    /// plot.add_error_bars(&[0.2, 0.5, 0.1]);
     ///
-    /// let len = 20.0; // The tick length will be of considerate length.
-    /// plot.set_x_axis_tick_length(len);
+    /// let width = 25.0; // The caps will be a quarter of the bar width.
+    /// plot.set_error_bar_cap_width(width);
     ///
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
-    pub fn set_x_axis_tick_length(&mut self, p: Percentage) {
-        self.layout.x_axis_tick_length = p;
+    pub fn set_error_bar_cap_width(&mut self, p: Percentage) {
+        self.layout.error_bar_cap_width = p;
     }
 
-    /// Anchor bars at zero instead of the floor.
-    /// This will make negative bars grow downwards.
-    /// If your dataset contains only negative values, then this might not make sense to use.
-    /// Rather, `use this when your dataset is likely to contain both positive and negative values`.
-    ///
-    /// An area where it makes sens is when visualizing temperature differences. :)
-    ///
-    /// By default, bars are anchored at the floor of the barchart.
-    /// However, you might want negative values to stand out by having them point downwards.
-    /// This method will apply anchoring bars at the zero line instead of the floor.
+    /// Set line width for the error bars.
     ///
-    /// # Important
-    /// Call [`BarPlot::set_scale_range`] first, and make sure to set `min < 0` and `max >= 0`.
-    /// Otherwise, you ~might~ will get a barchart that looks goofy. Consider yourself warned.
+    /// The width is calculated using a percentage.
+    /// A width of 100 (100%) will not affect the line width as it is the default.
+    /// You can either increase the width by passing a >100 value or decrease it with <100.
     ///
     /// # Example
     ///
@@ -1103,22 +3158,16 @@ impl <'a>BarPlot<'a> {
     ///
     /// let mut plot = BarPlot::new();
     ///
-    /// plot.add_values(&[5.0, -16.4, 17.1, 13.7, 8.9, 3.9, -6.3, 9.6]);
-    ///
-    /// // Adding some extra tweaks that makes this example more clear.
-    /// let min = -20;
-    /// let max = 20;
-    /// let step = 2;
-    /// plot.set_scale_range(min, max, step);
-    /// plot.set_plot_window_size(90.0, 80.0, 83.0, 50.0);
+    /// plot.add_values(&[1., 2., 3.,]);
+    /// plot.add_error_bars(&[0.2, 0.5, 0.1]);
     ///
-    /// // Negative bars will now grow downwards instead of upwards. :)
-    /// plot.set_negative_bars_go_down();
+    /// let width = 200.0; // Twice as thick as default.
+    /// plot.set_error_bar_line_width(width);
     ///
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
-    pub fn set_negative_bars_go_down(&mut self) {
-        self.layout.negative_bars_go_down = true;
+    pub fn set_error_bar_line_width(&mut self, p: Percentage) {
+        self.layout.error_bar_line_width = p;
     }
 
     /// Apply text on the left side of the plot window.
@@ -1389,7 +3438,7 @@ impl <'a>BarPlot<'a> {
     /// let svg: String = plot.to_svg(1600, 1000);
     /// ```
     pub fn set_legend(&mut self, categories: &'a [&'a str]) {
-        self.legend.categories = Some(categories);
+        self.legend.categories = Some(categories.to_vec());
     }
 
     /// Set legend position.
@@ -1521,6 +3570,68 @@ impl <'a>BarPlot<'a> {
         self.show.plot_border = true;
     }
 
+    /// Show lines joining the bars of a waterfall chart.
+    ///
+    /// A thin line is drawn from the end of each bar to the next bar, at the level of the running total.
+    /// This makes it easier to follow the running total from bar to bar.
+    /// The lines use the color from [`BarPlot::set_line_color`].
+    ///
+    /// # Important
+    ///
+    /// Only applies to waterfall charts, see [`BarPlot::set_bars_waterfall`].
+    /// The plot will panic on render if bars are drawn in any other mode.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[100., 20., -50., 0.]);
+    /// plot.set_bars_waterfall();
+    /// plot.set_waterfall_totals(&[3]);
+    ///
+    /// plot.set_show_waterfall_connectors();
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_show_waterfall_connectors(&mut self) {
+        self.show.waterfall_connectors = true;
+    }
+
+    /// Show the total of each bin as a label at the end of its stack.
+    ///
+    /// The total is the sum of all values in the bin, and it is placed above the stack
+    /// (or below it, if the total is negative). The label uses the color from [`BarPlot::set_text_color`].
+    ///
+    /// # Important
+    ///
+    /// Only applies with [`BarPlot::set_bars_stacked`] or [`BarPlot::set_bars_stacked_percent`],
+    /// and the plot will panic on render if bars are not stacked.
+    /// With [`BarPlot::set_bars_stacked_percent`], each stack will simply be labeled with 100.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[5., 16., 17., 8., 3.]);
+    /// plot.add_values(&[7., 6., 7., 16., 9.]);
+    ///
+    /// plot.set_bars_stacked();
+    /// plot.set_scale_range(0, 30, 5);
+    ///
+    /// plot.set_show_stack_totals();
+    ///
+    /// let svg: String = plot.to_svg(1600, 1000);
+    /// ```
+    pub fn set_show_stack_totals(&mut self) {
+        self.show.stack_totals = true;
+    }
+
     /// Generate the final svg image of all applied content.
     ///
     /// When you are satisfied with all the tweaks in above methods, it is time to generate the final svg.
@@ -1545,12 +3656,65 @@ impl <'a>BarPlot<'a> {
     /// svg = plot.to_svg(1600, 1000);
     /// ```
     pub fn to_svg(&mut self, width: u32, height: u32) -> String {
-        assert!(!self.values.is_empty(), "Can not generate plot without any values..");
+        self.size = (width, height);
+        self.assert_valid();
 
+        svg::render(self)
+    }
+
+    /// Generate a tiny svg image (a sparkline) with nothing but the bars.
+    ///
+    /// There are no texts, ticks, grid lines, legend or margins, so the bars fill the whole image.
+    /// This is meant for sizes such as 120 x 24, where text and lines would not be readable anyway,
+    /// e.g. for embedding a trend inline in a table.
+    ///
+    /// # How it operates
+    ///
+    /// The bars are scaled with [`BarPlot::set_scale_range`] if set, otherwise between zero and the values.
+    /// Bar colors, bar and bin gaps, stacked bars, the baseline and the background color are used as usual,
+    /// while everything else is left out.
+    ///
+    /// Note: check out [`BarPlot::set_sparkline_last_bar_color`] and [`BarPlot::set_sparkline_min_max_color`]
+    /// for highlighting the last bar and marking the lowest and highest value.
+    ///
+    /// # Important
+    ///
+    /// Radial charts, Pareto charts, population pyramids and bullet charts can not be drawn as sparklines.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::BarPlot;
+    ///
+    /// let mut plot = BarPlot::new();
+    ///
+    /// plot.add_values(&[4., 7., 5., 9., 6., 8., 3., 5., 6., 10.]);
+    /// plot.set_bin_gap(20.0);
+    ///
+    /// let svg: String = plot.to_sparkline_svg(120, 24);
+    /// ```
+    pub fn to_sparkline_svg(&mut self, width: u32, height: u32) -> String {
         self.size = (width, height);
+        self.assert_valid();
+
+        assert!(
+            !matches!(self.layout.bar_mode, BarMode::Pareto | BarMode::Pyramid | BarMode::Bullet),
+            "Can not draw a Pareto chart, population pyramid or bullet chart as a sparkline.",
+        );
+        assert!(
+            !matches!(self.layout.orientation, BarOrientation::Radial),
+            "Can not draw a radial chart as a sparkline.",
+        );
+
+        svg::render_sparkline(self)
+    }
+
+    // Catch invalid input and combinations of settings before anything is rendered.
+    fn assert_valid(&self) {
+        assert!(!self.values.is_empty(), "Can not generate plot without any values..");
 
         let n_categories = self.values.len();
-        match &mut self.colors.bars.layout {
+        match &self.colors.bars.layout {
             BarColorLayout::Category(colors) => {
                 let n_colors = colors.len();
                 assert_eq!(
@@ -1581,7 +3745,140 @@ impl <'a>BarPlot<'a> {
             _ => (),
         }
 
-        svg::render(self)
+        if let BarMode::StackedPercent = self.layout.bar_mode {
+            assert!(
+                self.values.iter().all(|values| values.iter().all(|f| *f >= 0.0)),
+                "Can not stack bars by percentage when there are negative values.",
+            );
+            assert!(
+                self.error_bars.is_empty(),
+                "Can not draw error bars when bars are stacked by percentage.",
+            );
+        }
+
+        if !matches!(self.layout.bar_mode, BarMode::Grouped | BarMode::Timeline) {
+            assert!(
+                self.range_starts.iter().all(|start| start.is_none()),
+                "Can not stack floating bars (ranges).",
+            );
+        }
+
+        if !matches!(self.layout.bar_mode, BarMode::Stacked | BarMode::StackedPercent) {
+            assert!(
+                self.stack_groups.iter().all(|group| *group == 0),
+                "Stack groups only apply when bars are stacked.",
+            );
+            assert!(!self.show.stack_totals, "Stack totals only apply when bars are stacked.");
+        }
+
+        if let BarMode::Waterfall = self.layout.bar_mode {
+            assert_eq!(n_categories, 1, "A waterfall chart takes exactly one set of values, got {n_categories}.");
+            let n_bins = self.values[0].len();
+            for bin in self.waterfall_totals.unwrap_or_default() {
+                assert!(*bin < n_bins, "Waterfall totals must be one of the {n_bins} bins, got bin {bin}.");
+            }
+        } else {
+            assert!(!self.show.waterfall_connectors, "Waterfall connectors only apply to waterfall charts.");
+        }
+
+        if let Some(n_bins) = self.values.first().map(|values| values.len()) {
+            for series in self.line_series.iter() {
+                let count = series.values.len();
+                assert_eq!(n_bins, count, "Line series should be same count as bins, expected {n_bins}, got {count}.");
+            }
+            for bin in self.points.iter().flat_map(|points| points.bins.iter()) {
+                assert!(*bin < n_bins, "Points must be placed in one of the {n_bins} bins, got bin {bin}.");
+            }
+            if let Some(edges) = &self.layout.bin_edges {
+                let n_edges = edges.len();
+                assert_eq!(n_bins + 1, n_edges, "Got {n_bins} bins and {n_edges} bin edges, expected one more edge than bins.");
+            }
+        }
+
+        if let BarStyle::Dumbbell = self.layout.bar_style {
+            assert_eq!(n_categories, 2, "A dumbbell chart takes exactly two sets of values, got {n_categories}.");
+            assert!(
+                matches!(self.layout.bar_mode, BarMode::Grouped),
+                "A dumbbell chart can only be drawn with grouped bars.",
+            );
+        }
+
+        if let BarMode::Pyramid = self.layout.bar_mode {
+            assert_eq!(n_categories, 2, "A population pyramid takes exactly two sets of values, got {n_categories}.");
+            assert!(
+                self.values.iter().all(|values| values.iter().all(|f| *f >= 0.0)),
+                "Can not draw a population pyramid when there are negative values.",
+            );
+            assert!(
+                matches!(self.layout.orientation, BarOrientation::Horizontal),
+                "A population pyramid is always drawn with horizontal bars.",
+            );
+            assert!(
+                self.error_bars.is_empty() && self.line_series.is_empty() && self.points.is_empty(),
+                "Can not draw error bars, line series or points on a population pyramid.",
+            );
+        } else {
+            assert!(self.layout.pyramid_gap.is_none(), "Pyramid bin markers in the middle only apply to population pyramids.");
+        }
+
+        if let BarMode::Bullet = self.layout.bar_mode {
+            assert_eq!(n_categories, 1, "A bullet chart takes exactly one set of values, got {n_categories}.");
+            let n_ranges = self.bullet_ranges.len();
+            assert!((2..=5).contains(&n_ranges), "A bullet chart takes two to five ranges, got {n_ranges}.");
+
+            let n_bins = self.values[0].len();
+            for (values, _) in self.bullet_ranges.iter() {
+                let count = values.len();
+                assert_eq!(n_bins, count, "Bullet ranges should be same count as bins, expected {n_bins}, got {count}.");
+            }
+            for pair in self.bullet_ranges.windows(2) {
+                assert!(
+                    pair[0].0.iter().zip(pair[1].0.iter()).all(|(low, high)| low <= high),
+                    "Bullet ranges must be added from the lowest to the highest.",
+                );
+            }
+            if let Some(targets) = self.bullet_targets {
+                let count = targets.len();
+                assert_eq!(n_bins, count, "Bullet targets should be same count as bins, expected {n_bins}, got {count}.");
+            }
+        } else {
+            assert!(
+                self.bullet_ranges.is_empty() && self.bullet_targets.is_none(),
+                "Bullet ranges and targets only apply to bullet charts.",
+            );
+        }
+
+        if let BarOrientation::Radial = self.layout.orientation {
+            assert!(
+                self.error_bars.is_empty() && self.curves.is_empty() && self.line_series.is_empty() && self.points.is_empty(),
+                "Can not draw error bars, curves, line series or points on a radial chart.",
+            );
+            assert!(self.wicks.iter().all(|wick| wick.is_none()), "Can not draw candlesticks or box plots on a radial chart.");
+            assert!(
+                !self.show.stack_totals && !self.show.waterfall_connectors,
+                "Can not show stack totals or waterfall connectors on a radial chart.",
+            );
+            assert!(
+                matches!(self.layout.bar_style, BarStyle::Rectangle),
+                "A radial chart can only be drawn with regular bars.",
+            );
+            assert!(
+                !matches!(self.layout.bar_mode, BarMode::Pareto | BarMode::Pyramid | BarMode::Bullet | BarMode::Timeline),
+                "Can not draw a Pareto chart, population pyramid, bullet chart or timeline as a radial chart.",
+            );
+        }
+
+        if let BarMode::Pareto = self.layout.bar_mode {
+            assert_eq!(n_categories, 1, "A Pareto chart takes exactly one set of values, got {n_categories}.");
+            assert!(
+                self.values[0].iter().all(|f| *f >= 0.0),
+                "Can not draw a Pareto chart when there are negative values.",
+            );
+            assert!(self.error_bars.is_empty(), "Can not draw error bars on a Pareto chart.");
+            assert!(self.line_series.is_empty(), "Can not draw line series on a Pareto chart.");
+            assert!(self.points.is_empty(), "Can not draw points on a Pareto chart.");
+            assert!(self.layout.bin_edges.is_none(), "Can not sort bins of different widths in a Pareto chart.");
+        }
     }
 }
 
@@ -1591,9 +3888,8 @@ mod tests {
 
     use std::fs;
 
-    use toml;
-
     use super::{VERSION, REPOSITORY};
+    use super::svg::format_time;
 
     #[test]
     fn version_and_repo() {
@@ -1607,4 +3903,28 @@ mod tests {
         let repository = value["package"]["repository"].as_str().unwrap();
         assert_eq!(repository, REPOSITORY);
     }
+
+    #[test]
+    fn time_labels() {
+        const HOUR: f64 = 3600.0;
+        const DAY: f64 = 86400.0;
+
+        // Midnight is labeled with the date, any other time of day with the time.
+        assert_eq!(format_time(0.0, HOUR), "1970-01-01");
+        assert_eq!(format_time(6.0 * HOUR, HOUR), "06:00");
+        assert_eq!(format_time(6.0 * HOUR + 30.0, 10.0), "06:00:30");
+
+        // With steps of a day or more, every label is a date.
+        assert_eq!(format_time(12.0 * HOUR, DAY), "1970-01-01");
+
+        // Leap years, including the ones every 400 years but not every 100 years.
+        assert_eq!(format_time(951_782_400.0, DAY), "2000-02-29");
+        assert_eq!(format_time(951_782_400.0 + DAY, DAY), "2000-03-01");
+        assert_eq!(format_time(4_107_542_400.0 - DAY, DAY), "2100-02-28");
+
+        // Before the epoch.
+        assert_eq!(format_time(-DAY, DAY), "1969-12-31");
+        assert_eq!(format_time(-1.0, 1.0), "23:59:59");
+        assert_eq!(format_time(-2_203_891_200.0, DAY), "1900-03-01");
+    }
 }
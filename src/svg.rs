@@ -3,13 +3,23 @@ mod tag;
 use crate::{
     BarColorLayout,
     BarColors,
+    BarMode,
+    BarOrientation,
     BarPlot,
+    BarStyle,
     BinMarkerPosition,
+    BoxPlot,
     Colors,
+    Curve,
+    ErrorBars,
+    LineSeries,
     LinesAt,
     Percentage,
+    PointShape,
+    Points,
     DEFAULT_BAR_COLOR,
     DEFAULT_LEGEND_POSITION,
+    DEFAULT_PERCENT_STEP,
     DEFAULT_TEXT_SIDE_OFFSET,
     REPOSITORY,
     VERSION,
@@ -22,17 +32,28 @@ enum Side {
     Bottom,
 }
 
-struct BarValues<'a> {
-    values: &'a [&'a [f64]],
+// Share of the bin width taken by the bar of a bullet chart, and by its target mark.
+const BULLET_BAR_WIDTH: f64 = 0.4;
+const BULLET_TARGET_WIDTH: f64 = 0.7;
+
+struct BarValues {
+    values: Vec<Vec<f64>>,
+    starts: Vec<Option<Vec<f64>>>, // Where each bar starts, for categories of floating bars.
+    wicks: Vec<Option<(Vec<f64>, Vec<f64>)>>, // The low and high end of each wick, for categories of candlesticks.
+    groups: Vec<usize>, // Which stack each category goes into, for stacked bars.
+    totals: Vec<usize>, // Bins holding the running total, for waterfall charts.
+    edges: Option<Vec<f64>>, // Where each bin starts and the last one ends, for bins of different widths.
     min: f64,
     max: f64,
     mean: f64,
 }
 
-impl <'a>BarValues<'a> {
-    fn from(values: &'a [&'a [f64]]) -> Self {
+impl BarValues {
+    fn from(values: &[&[f64]]) -> Self {
         assert!(!values.is_empty());
 
+        let values: Vec<Vec<f64>> = values.iter().map(|arr| arr.to_vec()).collect();
+
         let (mut max, mut min, mut sum) = (f64::MIN, f64::MAX, 0.0);
         let mut bar_count = 0;
         for arr in values.iter() {
@@ -44,18 +65,454 @@ impl <'a>BarValues<'a> {
             }
         }
         let mean = sum / bar_count as f64;
+        let starts = vec![None; values.len()];
+        let wicks = vec![None; values.len()];
+        let groups = vec![0; values.len()];
+
+        Self { values, starts, wicks, groups, totals: Vec::new(), edges: None, min, max, mean }
+    }
+
+    // Sort the bins in descending order, returning the original index of each bin in its new place.
+    fn sort_descending(&mut self) -> Vec<usize> {
+        let values = &self.values[0];
+        let mut order: Vec<usize> = (0..values.len()).collect();
+        order.sort_by(|a, b| values[*b].total_cmp(&values[*a]));
+
+        self.values[0] = order.iter().map(|i| values[*i]).collect();
+        order
+    }
+
+    // The running total of the first category as a percentage of its sum, for each bin.
+    fn cumulative_shares(&self) -> Vec<f64> {
+        let sum: f64 = self.values[0].iter().sum();
+        let mut running_total = 0.0;
+        self.values[0].iter().map(|f| {
+            running_total += f;
+            if sum == 0.0 { 0.0 } else { running_total / sum * 100.0 }
+        }).collect()
+    }
+
+    // Place the first category to the left of the middle and the second to the right, as floating bars.
+    // Both sides leave `offset` of room in the middle, for the bin markers.
+    fn from_pyramid(values: &[&[f64]], offset: f64) -> Self {
+        let left: Vec<f64> = values[0].iter().map(|f| -(f + offset)).collect();
+        let right: Vec<f64> = values[1].iter().map(|f| f + offset).collect();
+        let (left_starts, right_starts) = (vec![-offset; left.len()], vec![offset; right.len()]);
+
+        Self::from(&[&left, &right]).with_starts(&[Some(&left_starts), Some(&right_starts)])
+    }
+
+    // Turn deltas into floating bars, each starting where the running total of the previous bars ended.
+    // The bins listed in `totals` instead show the running total itself, growing from zero.
+    fn from_waterfall(deltas: &[f64], totals: &[usize]) -> Self {
+        let mut running_total = 0.0;
+        let (mut starts, mut ends) = (Vec::with_capacity(deltas.len()), Vec::with_capacity(deltas.len()));
+        for (bin_index, delta) in deltas.iter().enumerate() {
+            if totals.contains(&bin_index) {
+                starts.push(0.0);
+            } else {
+                starts.push(running_total);
+                running_total += delta;
+            }
+            ends.push(running_total);
+        }
+
+        let mut bar_values = Self::from(&[&ends]).with_starts(&[Some(&starts)]);
+        bar_values.totals = totals.to_vec();
+        bar_values
+    }
+
+    // Turn the ranges of a bullet chart into floating bars, each starting where the previous range ended.
+    // The first range starts at `floor`.
+    fn from_bullet_ranges(ranges: &[&[f64]], floor: f64) -> Self {
+        let mut starts: Vec<Vec<f64>> = vec![vec![floor; ranges[0].len()]];
+        starts.extend(ranges[..ranges.len() - 1].iter().map(|arr| arr.to_vec()));
+
+        let starts: Vec<Option<&[f64]>> = starts.iter().map(|arr| Some(arr.as_slice())).collect();
+        Self::from(ranges).with_starts(&starts)
+    }
 
-        Self { values, min, max, mean }
+    // Let categories with a range start float between the start and the value, instead of growing from the floor.
+    fn with_starts(mut self, starts: &[Option<&[f64]>]) -> Self {
+        self.starts = starts.iter().map(|arr| arr.map(|arr| arr.to_vec())).collect();
+        self
+    }
+
+    // Let categories of candlesticks have a wick from their low to their high value.
+    fn with_wicks(mut self, wicks: &[Option<(&[f64], &[f64])>]) -> Self {
+        self.wicks = wicks.iter().map(|wick| wick.map(|(low, high)| (low.to_vec(), high.to_vec()))).collect();
+        self
+    }
+
+    // Put each category into a stack group, so that stacked bars form one stack for each group in a bin.
+    fn with_groups(mut self, groups: &[usize]) -> Self {
+        self.groups = groups.to_vec();
+        self
+    }
+
+    // Same as `from`, but every value is replaced with its share (in percent) of its stack total.
+    fn from_shares(values: &[&[f64]], groups: &[usize]) -> Self {
+        let mut shares: Vec<Vec<f64>> = values.iter().map(|arr| arr.to_vec()).collect();
+        let group_count = groups.iter().max().map_or(1, |group| group + 1);
+
+        for bin_index in 0..values[0].len() {
+            for group in 0..group_count {
+                let in_group = |category_index: &usize| groups[*category_index] == group;
+                let total: f64 = (0..values.len()).filter(in_group).map(|i| values[i][bin_index]).sum();
+                // An empty stack stays empty instead of dividing by zero.
+                if total != 0.0 {
+                    for i in (0..values.len()).filter(in_group) {
+                        shares[i][bin_index] = values[i][bin_index] / total * 100.0;
+                    }
+                }
+            }
+        }
+
+        let shares: Vec<&[f64]> = shares.iter().map(|arr| arr.as_slice()).collect();
+        Self::from(&shares).with_groups(groups)
     }
 
     fn len(&self) -> usize {
         self.values[0].len()
     }
+
+    fn group_count(&self) -> usize {
+        self.groups.iter().max().map_or(1, |group| group + 1)
+    }
+
+    // Sum of all negative and all positive values in a stack group within a bin.
+    fn bin_stack(&self, bin_index: usize, group: usize) -> (f64, f64) {
+        let (mut negative, mut positive) = (0.0, 0.0);
+        for (arr, _) in self.values.iter().zip(self.groups.iter()).filter(|(_, g)| **g == group) {
+            let f = arr[bin_index];
+            if f >= 0.0 { positive += f } else { negative += f }
+        }
+        (negative, positive)
+    }
+
+    // The lowest and highest value that bars are scaled between.
+    fn value_range(&self, scale_range: Option<(f64, f64, f64)>, bar_mode: &BarMode) -> (f64, f64) {
+        if let Some((min, max, _)) = scale_range {
+            return (min, max);
+        }
+
+        match bar_mode {
+            BarMode::Grouped
+            | BarMode::Waterfall
+            | BarMode::Pareto
+            | BarMode::Pyramid
+            | BarMode::Bullet
+            | BarMode::Timeline => {
+                // Floating bars may start outside of the values, and wicks may reach beyond them.
+                let wicks = self.wicks.iter().flatten().flat_map(|(low, high)| low.iter().chain(high.iter()));
+                self.starts.iter().flatten().flatten().chain(wicks).fold((self.min, self.max), |(min, max), f| {
+                    (min.min(*f), max.max(*f))
+                })
+            }
+            BarMode::StackedPercent => (0.0, 100.0),
+            BarMode::Stacked => {
+                // Stacks always grow out from zero.
+                let (mut min, mut max) = (0.0_f64, 0.0_f64);
+                for bin_index in 0..self.len() {
+                    for group in 0..self.group_count() {
+                        let (negative, positive) = self.bin_stack(bin_index, group);
+                        min = min.min(negative);
+                        max = max.max(positive);
+                    }
+                }
+                // Stacks of only zeros still need some height to scale against.
+                (min, if max > min { max } else { min + 1.0 })
+            }
+        }
+    }
+
+    // The lowest and highest of all values and anything drawn on top of them (`overlay_values`).
+    fn value_extent(&self, bar_mode: &BarMode, overlay_values: impl Iterator<Item = f64>) -> (f64, f64) {
+        let (mut min, mut max) = self.value_range(None, bar_mode);
+        for f in overlay_values {
+            min = min.min(f);
+            max = max.max(f);
+        }
+        (min, max)
+    }
+
+    fn nice_scale_range(&self, bar_mode: &BarMode, overlay_values: impl Iterator<Item = f64>) -> (f64, f64, f64) {
+        nice_scale(self.value_extent(bar_mode, overlay_values))
+    }
+
+    // Number of bars (or stacks of bars) placed side by side in each bin.
+    fn slot_count(&self, bar_mode: &BarMode) -> usize {
+        match bar_mode {
+            BarMode::Grouped | BarMode::Waterfall | BarMode::Pareto => self.values.len(),
+            BarMode::Stacked | BarMode::StackedPercent => self.group_count(),
+            BarMode::Pyramid | BarMode::Bullet | BarMode::Timeline => 1,
+        }
+    }
+
+    // Which of the side by side bars (or stacks of bars) in a bin a category belongs to.
+    fn slot_index(&self, bar_mode: &BarMode, category_index: usize) -> usize {
+        match bar_mode {
+            BarMode::Grouped | BarMode::Waterfall | BarMode::Pareto => category_index,
+            BarMode::Stacked | BarMode::StackedPercent => self.groups[category_index],
+            BarMode::Pyramid | BarMode::Bullet | BarMode::Timeline => 0,
+        }
+    }
+}
+
+fn bar_color<'a>(bar_colors: &BarColors<'a>, bar_values: &BarValues, bar: &Bar) -> &'a str {
+    for (i, j, color) in bar_colors.overrides.iter().copied() {
+        if bar.category_index == i && bar.bar_index == j {
+            return color;
+        }
+    }
+
+    match &bar_colors.layout {
+        BarColorLayout::Category(arr) => {
+            arr[bar.category_index]
+        }
+        BarColorLayout::Indexed(arr) => {
+            arr[bar.category_index][bar.bar_index]
+        }
+        BarColorLayout::Threshold((clr_min, clr_low, clr_high, clr_max)) => {
+            if bar.value == bar_values.max { clr_max }
+            else if bar.value == bar_values.min { clr_min }
+            else if bar.value >= bar_values.mean { clr_high }
+            else { clr_low }
+        }
+        BarColorLayout::Uniform(color) => {
+            color
+        }
+        BarColorLayout::Baseline((clr_above, clr_below)) | BarColorLayout::Candlestick((clr_above, clr_below)) => {
+            // A bar below the baseline (or closing below its opening) has its tip at the bottom.
+            if bar.tip == bar.start && bar.start != bar.end { clr_below }
+            else { clr_above }
+        }
+        BarColorLayout::Waterfall((clr_increase, clr_decrease, clr_total)) => {
+            // A bar that went down has its tip at the bottom.
+            if bar_values.totals.contains(&bar.bar_index) { clr_total }
+            else if bar.tip == bar.start && bar.start != bar.end { clr_decrease }
+            else { clr_increase }
+        }
+    }
+}
+
+// Bar colors for bins placed in a new `order`, where `order[i]` is the original index of the bin now at `i`.
+fn sorted_bar_colors<'a>(bar_colors: &BarColors<'a>, order: &[usize]) -> BarColors<'a> {
+    let layout = match &bar_colors.layout {
+        BarColorLayout::Indexed(arr) => {
+            BarColorLayout::Indexed(arr.iter().map(|colors| order.iter().map(|i| colors[*i]).collect()).collect())
+        }
+        layout => layout.clone(),
+    };
+    let overrides = bar_colors.overrides
+        .iter()
+        .filter_map(|(i, j, color)| Some((*i, order.iter().position(|bin| bin == j)?, *color)))
+        .collect();
+
+    BarColors { layout, overrides }
+}
+
+// Every number from min to max (both included) with step in between.
+fn scale_steps(min: f64, max: f64, step: f64) -> impl Iterator<Item = f64> {
+    assert!(step > 0.0, "Scale step must be greater than zero.");
+    // Allow for a tiny rounding error so that max is not skipped.
+    let last = max + (step / 1e6);
+    // Stop if the step is too small to move a number this large any further.
+    let mut previous = f64::NEG_INFINITY;
+    (0..).map(move |i| min + (step * i as f64)).take_while(move |n| {
+        let moved = *n > previous;
+        previous = *n;
+        moved && *n <= last
+    })
+}
+
+// Format a number on the scale with as many decimals as the step needs e.g. 0.25 -> 2 and 10 -> 0.
+fn format_scale_number(f: f64, step: f64) -> String {
+    let decimals = (0..6).find(|d| {
+        let shifted = step * 10_f64.powi(*d);
+        (shifted - shifted.round()).abs() < 1e-6
+    }).unwrap_or(6) as usize;
+    // Adding zero turns negative zero into positive zero.
+    format!("{:.*}", decimals, f + 0.0)
+}
+
+// A scale that covers both `min`, `max` and zero, with a step of 1, 2, 2.5 or 5 times a power of ten.
+pub fn nice_scale((min, max): (f64, f64)) -> (f64, f64, f64) {
+    let (min, max) = (min.min(0.0), max.max(0.0));
+    // All values are zero, give the scale some room above them.
+    let max = if max > min { max } else { min + 1.0 };
+    let range = max - min;
+
+    // Aim for no more than 10 steps.
+    let rough_step = range / 10.0;
+    let magnitude = 10_f64.powf(rough_step.log10().floor());
+    let step = [1.0, 2.0, 2.5, 5.0, 10.0]
+        .into_iter()
+        .map(|f| f * magnitude)
+        .find(|step| *step >= rough_step)
+        .unwrap_or(10.0 * magnitude);
+
+    ((min / step).floor() * step, (max / step).ceil() * step, step)
+}
+
+// Steps for a scale of seconds, from a second up to a year.
+const TIME_STEPS: [f64; 22] = [
+    1.0, 5.0, 10.0, 15.0, 30.0,
+    60.0, 300.0, 600.0, 900.0, 1800.0,
+    3600.0, 7200.0, 10800.0, 21600.0, 43200.0,
+    86400.0, 172800.0, 604800.0, 1209600.0, 2592000.0, 7776000.0, 31536000.0,
+];
+
+// A scale of seconds that covers both `min` and `max`, with a step such as 15 minutes, 1 hour or 1 day.
+// Unlike `nice_scale`, zero is left out as it is just the Unix epoch.
+pub fn nice_time_scale((min, max): (f64, f64)) -> (f64, f64, f64) {
+    // Without any tasks (e.g. all are `NaN`), min and max are left at the far ends of f64, so show the first day.
+    let (min, max) = if min <= max { (min, max) } else { (0.0, 86400.0) };
+    let range = if max > min { max - min } else { 1.0 };
+
+    // Aim for no more than 10 steps.
+    let rough_step = range / 10.0;
+    let year = TIME_STEPS[TIME_STEPS.len() - 1];
+    let step = TIME_STEPS
+        .into_iter()
+        .find(|step| *step >= rough_step)
+        .unwrap_or((rough_step / year).ceil() * year);
+
+    // A single point in time still needs at least one step to scale against.
+    let (min, max) = ((min / step).floor() * step, (max / step).ceil() * step);
+    (min, if max > min { max } else { min + step }, step)
+}
+
+// Seconds since the Unix epoch as a date (YYYY-MM-DD) if the step is a day or longer, otherwise as a time of day.
+// Times of day at midnight are shown as the date instead, to tell the days apart.
+pub fn format_time(seconds: f64, step: f64) -> String {
+    const DAY: i64 = 86400;
+    let seconds = seconds.round() as i64;
+    let (days, time_of_day) = (seconds.div_euclid(DAY), seconds.rem_euclid(DAY));
+
+    if step >= DAY as f64 || time_of_day == 0 {
+        let (year, month, day) = civil_from_days(days);
+        return format!("{year}-{month:02}-{day:02}");
+    }
+
+    let (hour, minute, second) = (time_of_day / 3600, time_of_day % 3600 / 60, time_of_day % 60);
+    if step < 60.0 {
+        format!("{hour:02}:{minute:02}:{second:02}")
+    } else {
+        format!("{hour:02}:{minute:02}")
+    }
+}
+
+// Year, month and day from days since the Unix epoch, in the proleptic Gregorian calendar.
+// See Howard Hinnant's `civil_from_days` (http://howardhinnant.github.io/date_algorithms.html).
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153; // March is 0.
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+// Round to two decimals and drop trailing zeros e.g. 12.0 -> "12" and 0.3000001 -> "0.3".
+pub fn format_number(f: f64) -> String {
+    // Adding zero turns negative zero into positive zero.
+    ((f * 100.0).round() / 100.0 + 0.0).to_string()
+}
+
+// Maps values onto the value axis, where `unit` is the length of a single step of 1.
+struct ValueScale {
+    min: f64,
+    unit: f64,
+}
+
+impl ValueScale {
+    // Distance from the start of the value axis (bottom for vertical bars, left for horizontal bars).
+    fn pos(&self, f: f64) -> f64 {
+        (f - self.min) * self.unit
+    }
+}
+
+// Where bins are placed along the bin axis, measured from its start (left for vertical bars, top for horizontal bars).
+enum BinScale {
+    Even(f64), // All bins have the same width.
+    Uneven(Vec<f64>), // Where each bin starts and the last one ends, for bins of different widths.
+}
+
+impl BinScale {
+    fn start(&self, bin: usize) -> f64 {
+        match self {
+            Self::Even(width) => width * bin as f64,
+            Self::Uneven(edges) => edges[bin],
+        }
+    }
+
+    // Passing one past the last bin gives the width of the last bin.
+    fn width(&self, bin: usize) -> f64 {
+        match self {
+            Self::Even(width) => *width,
+            Self::Uneven(edges) => {
+                let bin = bin.min(edges.len() - 2);
+                edges[bin + 1] - edges[bin]
+            }
+        }
+    }
+
+    // Position given in bins, where 0 is the start of the first bin and 1.5 is the middle of the second bin.
+    fn pos(&self, bin_pos: f64) -> f64 {
+        match self {
+            Self::Even(width) => bin_pos * width,
+            Self::Uneven(edges) => {
+                let bin = (bin_pos.max(0.0) as usize).min(edges.len() - 2);
+                edges[bin] + ((bin_pos - bin as f64) * self.width(bin))
+            }
+        }
+    }
+
+    // Middle of a bin.
+    fn center(&self, bin: usize) -> f64 {
+        self.pos(bin as f64 + 0.5)
+    }
+}
+
+// Spreads points sideways by a pseudo random amount, the same on every render so that output is reproducible.
+#[derive(Default)]
+struct Jitter {
+    state: u32,
+}
+
+impl Jitter {
+    // Offset between -0.5 and 0.5.
+    fn next_offset(&mut self) -> f64 {
+        // Linear congruential generator (Numerical Recipes).
+        self.state = self.state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        (self.state >> 8) as f64 / (1u32 << 24) as f64 - 0.5
+    }
+}
+
+// A bar laid out along the bin axis, spanning from `start` to `end` on the value axis.
+struct Bar {
+    category_index: usize,
+    bar_index: usize,
+    value: f64,
+    pos: f64, // Where the bar starts along the bin axis.
+    width: f64,
+    start: f64,
+    end: f64,
+    tip: f64, // The end of the bar where its value is read (the top of a positive bar).
 }
 
 struct SvgGenerator {
     svg_window: (f64, f64, f64, f64),
     plot_window: Option<(f64, f64, f64, f64)>,
+    horizontal: bool,
+    radial: bool,
+    time_axis: bool, // Scale values are seconds since the Unix epoch.
+    radial_rotation: f64, // Radians, clockwise.
     nodes: Vec<String>,
 }
 
@@ -64,6 +521,10 @@ impl SvgGenerator {
         Self {
             svg_window: (0.0, svg_width, 0.0, svg_height),
             plot_window: None,
+            horizontal: false,
+            radial: false,
+            time_axis: false,
+            radial_rotation: 0.0,
             nodes: Vec::with_capacity(200),
         }
     }
@@ -86,210 +547,829 @@ impl SvgGenerator {
         y2-y1
     }
 
-    fn get_font_size(&self, font_size: Percentage) -> f64 {
-        (self.get_svg_width() * self.get_svg_height()).sqrt() / 50.0 * (font_size / 100.0)
+    // Length of the axis where bins are laid out (x-axis for vertical bars, y-axis for horizontal bars).
+    fn get_bin_axis_length(&self) -> f64 {
+        if self.horizontal { self.get_plot_height() } else { self.get_plot_width() }
+    }
+
+    fn get_bin_scale(&self, bar_values: &BarValues) -> BinScale {
+        let length = self.get_bin_axis_length();
+        match &bar_values.edges {
+            Some(edges) => {
+                let (first, last) = (edges[0], edges[edges.len() - 1]);
+                let unit = length / (last - first);
+                BinScale::Uneven(edges.iter().map(|edge| (edge - first) * unit).collect())
+            }
+            None => BinScale::Even(length / bar_values.len() as f64),
+        }
+    }
+
+    // Length of the axis where values are measured (y-axis for vertical bars, x-axis for horizontal bars).
+    fn get_value_axis_length(&self) -> f64 {
+        if self.horizontal { self.get_plot_width() } else { self.get_plot_height() }
+    }
+
+    // Translate a position along the bin axis and the value axis into a point on the canvas.
+    // The bin axis starts at the left (vertical) or top (horizontal) side of the plot window,
+    // while the value axis starts at the bottom (vertical) or left (horizontal) side.
+    fn place_point(&self, bin_pos: f64, value_pos: f64) -> (f64, f64) {
+        let (x1, _, y1, y2) = self.plot_window.unwrap_or(self.svg_window);
+        if self.horizontal {
+            (x1 + value_pos, y1 + bin_pos)
+        } else {
+            (x1 + bin_pos, y2 - value_pos)
+        }
+    }
+
+    // Same as `place_point`, but for a rectangle spanning `bin_len` and `value_len` on respective axis.
+    fn place_rect(&self, bin_pos: f64, bin_len: f64, value_pos: f64, value_len: f64) -> (f64, f64, f64, f64) {
+        if self.horizontal {
+            let (x, y) = self.place_point(bin_pos, value_pos);
+            (x, y, value_len, bin_len)
+        } else {
+            let (x, y) = self.place_point(bin_pos, value_pos + value_len);
+            (x, y, bin_len, value_len)
+        }
+    }
+
+    fn format_scale_label(&self, n: f64, step: f64) -> String {
+        if self.time_axis { format_time(n, step) } else { format_scale_number(n, step) }
+    }
+
+    fn set_orientation(&mut self, orientation: &BarOrientation) {
+        self.horizontal = matches!(orientation, BarOrientation::Horizontal);
+        self.radial = matches!(orientation, BarOrientation::Radial);
+    }
+
+    // Center and outer radius of a radial plot, the largest circle fitting the plot window.
+    fn get_radial_frame(&self) -> (f64, f64, f64) {
+        let (x1, _, y1, _) = self.plot_window.unwrap_or(self.svg_window);
+        let (width, height) = (self.get_plot_width(), self.get_plot_height());
+        (x1 + (width / 2.0), y1 + (height / 2.0), width.min(height) / 2.0)
+    }
+
+    // Same as `place_point`, but for radial plots where the bin axis goes around the circle (clockwise from the top)
+    // and the value axis goes from the center and outwards. Returns the angle (radians) and radius.
+    fn place_polar(&self, bin_pos: f64, value_pos: f64) -> (f64, f64) {
+        let (_, _, radius) = self.get_radial_frame();
+        let angle = bin_pos / self.get_bin_axis_length() * std::f64::consts::TAU + self.radial_rotation;
+        (angle, value_pos / self.get_value_axis_length() * radius)
+    }
+
+    fn polar_to_point(&self, angle: f64, radius: f64) -> (f64, f64) {
+        let (cx, cy, _) = self.get_radial_frame();
+        (cx + radius * angle.sin(), cy - radius * angle.cos())
+    }
+
+    fn get_font_size(&self, font_size: Percentage) -> f64 {
+        (self.get_svg_width() * self.get_svg_height()).sqrt() / 50.0 * (font_size / 100.0)
+    }
+
+    fn get_base_line_width(&self) -> f64 {
+        (self.get_svg_width() * self.get_svg_height()).sqrt() / 100.0
+    }
+
+    fn set_background_color(&mut self, color: &str) {
+        let (width, height) = (self.get_svg_width(), self.get_svg_height());
+        let rect = tag::rect(0.0, 0.0, width, height, 1.0, color);
+        self.nodes.push(rect);
+    }
+
+    fn set_plot_window(&mut self, x_size: Percentage, x_offset: Percentage, y_size: Percentage, y_offset: Percentage) {
+        // Calculate the plot window size and offset from given percentage.
+        assert!(x_size <= 100.0 && x_offset <= 100.0, "x_size and or x_offset cannot exceed 100%");
+        assert!(y_size <= 100.0 && y_offset <= 100.0, "y_size and or y_offset cannot exceed 100%");
+
+        let x_length = self.get_svg_width() * x_size / 100.0;
+        let x_offset = ((self.get_svg_width() * (1.0 - x_size / 100.0)) / 100.0) * x_offset;
+
+        let y_length = self.get_svg_height() * y_size / 100.0;
+        let y_offset = ((self.get_svg_height() * (1.0 - y_size / 100.0)) / 100.0) * y_offset;
+
+        let (x1, x2, y1, y2) = (x_offset, x_offset + x_length, y_offset, y_length + y_offset);
+
+        self.plot_window = Some((x1, x2, y1, y2));
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn generate_scale_range(
+        &mut self,
+        min: f64,
+        max: f64,
+        step: f64,
+        unit: &str,
+        axis_offset: Percentage,
+        show_grid_lines: bool,
+        line_color: &str,
+        tick_color: &str,
+        text_color: &str,
+        font_size: Percentage,
+    ) {
+        let (x1, x2, y1, y2) = self.plot_window.unwrap_or(self.svg_window);
+        let range = max - min;
+        let scale_unit = self.get_value_axis_length() / range;
+        let line_width = self.get_base_line_width() / 10.0;
+        let font_size = self.get_font_size(font_size);
+        let font_fraction = font_size / 3.5;
+
+        if self.horizontal {
+            let y3 = y2 + ((self.get_svg_height() - y2) / 100.0 * axis_offset); // tick bottom end
+            for n in scale_steps(min, max, step) {
+                let cur_x = x1 + ((n - min) * scale_unit);
+
+                if show_grid_lines {
+                    let tag = tag::line(cur_x, cur_x, y1, y2, line_color, line_width);
+                    self.nodes.push(tag);
+                }
+
+                // If offset is 0, no point in rendering the tick.
+                if axis_offset != 0.0 {
+                    let tag = tag::line(cur_x, cur_x, y2, y3, tick_color, line_width);
+                    self.nodes.push(tag);
+                }
+
+                let num = &format!("{}{unit}", self.format_scale_label(n, step));
+                let tag = tag::text(cur_x, y3 + font_size, text_color, font_size, "middle", num);
+                self.nodes.push(tag);
+            }
+            return;
+        }
+
+        if self.radial {
+            // Circles around the center, labeled straight upwards.
+            for n in scale_steps(min, max, step) {
+                let (_, radius) = self.place_polar(0.0, (n - min) * scale_unit);
+                let (x, y) = self.polar_to_point(0.0, radius);
+
+                if show_grid_lines {
+                    let (cx, cy, _) = self.get_radial_frame();
+                    let tag = tag::ring(cx, cy, radius, line_color, line_width);
+                    self.nodes.push(tag);
+                }
+
+                // Put the label just inside the circle, to keep it clear of the bin markers.
+                let num = &format!("{}{unit}", self.format_scale_label(n, step));
+                let tag = tag::text(x + font_fraction, y + font_size, text_color, font_size, "start", num);
+                self.nodes.push(tag);
+            }
+            return;
+        }
+
+        let x3 = (x1 / 100.0) * (100.0 - axis_offset); // tick left end
+        for n in scale_steps(min, max, step) {
+            let cur_y = y2 - ((n - min) * scale_unit);
+
+            if show_grid_lines {
+                let tag = tag::line(x1, x2, cur_y, cur_y, line_color, line_width);
+                self.nodes.push(tag);
+            }
+
+            // If offset is 0, no point in rendering the tick.
+            if axis_offset != 0.0 {
+                let tag = tag::line(x3, x1, cur_y, cur_y, tick_color, line_width);
+                self.nodes.push(tag);
+            }
+
+            let num = &format!("{}{unit}", self.format_scale_label(n, step));
+            let tag = tag::text(x3 - font_fraction, cur_y + font_fraction, text_color, font_size, "end", num);
+            self.nodes.push(tag);
+        }
+    }
+
+    // Percentage axis on the opposite side of the regular scale, from 0 to 100%.
+    fn generate_secondary_scale(
+        &mut self,
+        step: f64,
+        axis_offset: Percentage,
+        tick_color: &str,
+        text_color: &str,
+        font_size: Percentage,
+    ) {
+        let (x1, x2, y1, y2) = self.plot_window.unwrap_or(self.svg_window);
+        let scale_unit = self.get_value_axis_length() / 100.0;
+        let line_width = self.get_base_line_width() / 10.0;
+        let font_size = self.get_font_size(font_size);
+        let font_fraction = font_size / 3.5;
+
+        if self.horizontal {
+            let y3 = y1 - (y1 / 100.0 * axis_offset); // tick top end
+            for n in scale_steps(0.0, 100.0, step) {
+                let cur_x = x1 + (n * scale_unit);
+
+                // If offset is 0, no point in rendering the tick.
+                if axis_offset != 0.0 {
+                    let tag = tag::line(cur_x, cur_x, y3, y1, tick_color, line_width);
+                    self.nodes.push(tag);
+                }
+
+                let num = &format!("{}%", format_scale_number(n, step));
+                let tag = tag::text(cur_x, y3 - font_fraction, text_color, font_size, "middle", num);
+                self.nodes.push(tag);
+            }
+            return;
+        }
+
+        let x3 = x2 + ((self.get_svg_width() - x2) / 100.0 * axis_offset); // tick right end
+        for n in scale_steps(0.0, 100.0, step) {
+            let cur_y = y2 - (n * scale_unit);
+
+            // If offset is 0, no point in rendering the tick.
+            if axis_offset != 0.0 {
+                let tag = tag::line(x2, x3, cur_y, cur_y, tick_color, line_width);
+                self.nodes.push(tag);
+            }
+
+            let num = &format!("{}%", format_scale_number(n, step));
+            let tag = tag::text(x3 + font_fraction, cur_y + font_fraction, text_color, font_size, "start", num);
+            self.nodes.push(tag);
+        }
+    }
+
+    // Same scale on both sides of the middle, growing outwards. Only for horizontal bars.
+    #[allow(clippy::too_many_arguments)]
+    fn generate_pyramid_scale(
+        &mut self,
+        max: f64,
+        step: f64,
+        offset: f64,
+        value_scale: &ValueScale,
+        axis_offset: Percentage,
+        show_grid_lines: bool,
+        line_color: &str,
+        tick_color: &str,
+        text_color: &str,
+        font_size: Percentage,
+    ) {
+        let (x1, _, y1, y2) = self.plot_window.unwrap_or(self.svg_window);
+        let line_width = self.get_base_line_width() / 10.0;
+        let font_size = self.get_font_size(font_size);
+
+        let y3 = y2 + ((self.get_svg_height() - y2) / 100.0 * axis_offset); // tick bottom end
+        for n in scale_steps(0.0, max, step) {
+            for side in [-1.0, 1.0] {
+                // Zero is shared by both sides when there is no room in the middle.
+                if n == 0.0 && offset == 0.0 && side < 0.0 {
+                    continue;
+                }
+                let cur_x = x1 + value_scale.pos(side * (offset + n));
+
+                if show_grid_lines {
+                    let tag = tag::line(cur_x, cur_x, y1, y2, line_color, line_width);
+                    self.nodes.push(tag);
+                }
+
+                // If offset is 0, no point in rendering the tick.
+                if axis_offset != 0.0 {
+                    let tag = tag::line(cur_x, cur_x, y2, y3, tick_color, line_width);
+                    self.nodes.push(tag);
+                }
+
+                let num = &format_scale_number(n, step);
+                let tag = tag::text(cur_x, y3 + font_size, text_color, font_size, "middle", num);
+                self.nodes.push(tag);
+            }
+        }
+    }
+
+    // Bin markers placed in the middle of a pyramid, between the two sides. Only for horizontal bars.
+    fn generate_pyramid_markers(&mut self, bar_values: &BarValues, markers: &[&str], text_color: &str, font_size: Percentage) {
+        let (x1, _, y1, _) = self.plot_window.unwrap_or(self.svg_window);
+        let font_size = self.get_font_size(font_size);
+        let font_fraction = font_size / 3.5;
+        let x = x1 + (self.get_value_axis_length() / 2.0);
+
+        let bin_scale = self.get_bin_scale(bar_values);
+        for (i, text) in markers.iter().enumerate() {
+            let y = y1 + bin_scale.start(i) + (bin_scale.width(i) / 2.0);
+            let tag = tag::text(x, y + font_fraction, text_color, font_size, "middle", text);
+            self.nodes.push(tag);
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn generate_bin_markers(
+            &mut self,
+            bar_values: &BarValues,
+            markers: &[&str],
+            axis_offset: Percentage,
+            bin_marker_position: &BinMarkerPosition,
+            show_grid_lines: bool,
+            colors: &Colors,
+            font_size: Percentage,
+        ) {
+        let (x1, x2, y1, y2) = self.plot_window.unwrap_or(self.svg_window);
+
+        let line_width = self.get_base_line_width() / 10.0;
+        let font_size = self.get_font_size(font_size);
+        let font_fraction = font_size / 3.5;
+
+        let bin_scale = self.get_bin_scale(bar_values);
+        let marker_shift = |i: usize| match bin_marker_position {
+            BinMarkerPosition::Middle => bin_scale.width(i) / 2.0,
+            BinMarkerPosition::Left => 0.0,
+            BinMarkerPosition::Right => bin_scale.width(i),
+        };
+
+        if self.radial {
+            // Markers go around the circle, just outside of it.
+            let (cx, cy, radius) = self.get_radial_frame();
+            for (i, text) in markers.iter().enumerate() {
+                let (angle, _) = self.place_polar(bin_scale.start(i) + marker_shift(i), 0.0);
+
+                if show_grid_lines {
+                    let (x, y) = self.polar_to_point(angle, radius);
+                    let tag = tag::line(cx, x, cy, y, colors.line, line_width);
+                    self.nodes.push(tag);
+                }
+
+                let (x, y) = self.polar_to_point(angle, radius + font_size);
+                let tag = tag::text(x, y + font_fraction, colors.text, font_size, "middle", text);
+                self.nodes.push(tag);
+            }
+            return;
+        }
+
+        if self.horizontal {
+            let x3 = (x1 / 100.0) * (100.0 - axis_offset); // tick left end
+            for (i, text) in markers.iter().enumerate() {
+                let cur_y = y1 + bin_scale.start(i) + marker_shift(i);
+
+                if show_grid_lines {
+                    let tag = tag::line(x1, x2, cur_y, cur_y, colors.line, line_width);
+                    self.nodes.push(tag);
+                }
+
+                // If offset is 0, no point in rendering the tick.
+                if axis_offset != 0. {
+                    let tag = tag::line(x3, x1, cur_y, cur_y, colors.tick, line_width);
+                    self.nodes.push(tag);
+                }
+
+                let tag = tag::text(x3 - font_fraction, cur_y + font_fraction, colors.text, font_size, "end", text);
+                self.nodes.push(tag);
+            }
+            return;
+        }
+
+        let y3 = y2 + ((self.get_svg_height() - y2) / 100.0 * axis_offset);
+        for (i, text) in markers.iter().enumerate() {
+            let cur_x = x1 + bin_scale.start(i) + marker_shift(i);
+
+            if show_grid_lines {
+                let tag = tag::line(cur_x, cur_x, y1, y2, colors.line, line_width);
+                self.nodes.push(tag);
+            }
+
+            // If offset is 0, no point in rendering the tick.
+            if axis_offset != 0. {
+                let tag = tag::line(cur_x, cur_x, y2, y3, colors.tick, line_width);
+                self.nodes.push(tag);
+            }
+
+            let tag = tag::text(cur_x, y3 + font_size, colors.text, font_size, "middle", text);
+            self.nodes.push(tag);
+        }
+    }
+
+    fn get_value_scale(
+        &self,
+        bar_values: &BarValues,
+        scale_range: Option<(f64, f64, f64)>,
+        bar_mode: &BarMode,
+    ) -> ValueScale {
+        let (min, max) = bar_values.value_range(scale_range, bar_mode);
+        ValueScale { min, unit: self.get_value_axis_length() / (max - min) }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn layout_bars(
+        &self,
+        bar_values: &BarValues,
+        value_scale: &ValueScale,
+        baseline: Option<f64>,
+        bar_mode: &BarMode,
+        bin_gap: Percentage,
+        bar_gap: Percentage,
+    ) -> Vec<Bar> {
+        let bin_scale = self.get_bin_scale(bar_values);
+
+        // Each slot holds one bar, or one stack of bars, within a bin.
+        let slots = bar_values.slot_count(bar_mode);
+
+        // Where the negative and positive part of each stack currently ends, indexed by slot and bin.
+        let mut stack_ends: Vec<Vec<(f64, f64)>> = vec![vec![(0.0, 0.0); bar_values.len()]; slots];
+
+        let mut bars = Vec::with_capacity(bar_values.len() * bar_values.values.len());
+
+        for (category_index, values) in bar_values.values.iter().enumerate() {
+            let slot_index = bar_values.slot_index(bar_mode, category_index);
+            for (bar_index, bar_value) in values.iter().copied().enumerate() {
+                // A bar that is not a number is left out, e.g. an empty row on a timeline.
+                let start_is_nan = bar_values.starts[category_index].as_ref().is_some_and(|starts| starts[bar_index].is_nan());
+                if bar_value.is_nan() || start_is_nan {
+                    continue;
+                }
+
+                let bin_width = bin_scale.width(bar_index);
+                let bin_margin = bin_width * (bin_gap / 100.0);
+                let margined_bin_width = (bin_width - bin_margin) / slots as f64;
+                let bar_margin = (bin_width - bin_margin) / slots as f64 * (bar_gap / 100.0);
+                let bar_width = margined_bin_width - bar_margin;
+
+                let x3 = bin_margin - (bin_margin / 2.0) + (bar_margin / 2.0);
+                let x4 = x3 + (margined_bin_width * slot_index as f64);
+                let pos = x4 + bin_scale.start(bar_index);
+
+                let (start, end, tip) = match bar_mode {
+                    BarMode::Stacked | BarMode::StackedPercent => {
+                        // Positive values stack upwards from zero, negative values stack downwards.
+                        let (negative_end, positive_end) = &mut stack_ends[slot_index][bar_index];
+                        let stack_end = if bar_value >= 0.0 { positive_end } else { negative_end };
+                        let (start, tip) = (*stack_end, *stack_end + bar_value);
+                        *stack_end = tip;
+                        (start.min(tip), start.max(tip), tip)
+                    }
+                    BarMode::Grouped
+                    | BarMode::Waterfall
+                    | BarMode::Pareto
+                    | BarMode::Pyramid
+                    | BarMode::Bullet
+                    | BarMode::Timeline => {
+                        if let Some(starts) = &bar_values.starts[category_index] {
+                            let start = starts[bar_index];
+                            (start.min(bar_value), start.max(bar_value), bar_value)
+                        } else if let Some(baseline) = baseline {
+                            // Bars grow up or down from the baseline, depending on which side of it they end.
+                            (bar_value.min(baseline), bar_value.max(baseline), bar_value)
+                        } else {
+                            (value_scale.min, bar_value, bar_value)
+                        }
+                    }
+                };
+
+                bars.push(Bar { category_index, bar_index, value: bar_value, pos, width: bar_width, start, end, tip });
+            }
+        }
+
+        bars
+    }
+
+    fn generate_bars(&mut self, bars: &[Bar], value_scale: &ValueScale, bar_values: &BarValues, bar_colors: &BarColors) {
+        // FIXME: Let user set custom opacity.
+        let opacity = 1.0;
+        for bar in bars {
+            let bar_color = bar_color(bar_colors, bar_values, bar);
+
+            if self.radial {
+                let (cx, cy, _) = self.get_radial_frame();
+                let (a1, r1) = self.place_polar(bar.pos, value_scale.pos(bar.start));
+                let (a2, r2) = self.place_polar(bar.pos + bar.width, value_scale.pos(bar.end));
+                self.nodes.push(tag::sector(cx, cy, r1, r2, a1, a2, bar_color));
+                continue;
+            }
+
+            let value_pos = value_scale.pos(bar.start);
+            let value_len = (bar.end - bar.start) * value_scale.unit;
+            let (x, y, width, height) = self.place_rect(bar.pos, bar.width, value_pos, value_len);
+            let tag = tag::rect(x, y, width, height, opacity, bar_color);
+            self.nodes.push(tag);
+        }
+    }
+
+    fn generate_lollipops(&mut self, bars: &[Bar], value_scale: &ValueScale, bar_values: &BarValues, bar_colors: &BarColors) {
+        let stem_width = self.get_base_line_width() / 5.0;
+        for bar in bars {
+            let bar_color = bar_color(bar_colors, bar_values, bar);
+            let bin_pos = bar.pos + (bar.width / 2.0);
+
+            let (x1, y1) = self.place_point(bin_pos, value_scale.pos(bar.start));
+            let (x2, y2) = self.place_point(bin_pos, value_scale.pos(bar.end));
+            self.nodes.push(tag::line(x1, x2, y1, y2, bar_color, stem_width));
+
+            let (x, y) = self.place_point(bin_pos, value_scale.pos(bar.tip));
+            let radius = self.get_dot_radius(bar.width);
+            self.nodes.push(tag::circle(x, y, radius, bar_color));
+        }
+    }
+
+    fn generate_dumbbells(
+        &mut self,
+        bars: &[Bar],
+        value_scale: &ValueScale,
+        bar_values: &BarValues,
+        bar_colors: &BarColors,
+        line_color: &str,
+    ) {
+        let width = self.get_base_line_width() / 5.0;
+        // Pair the two bars of each bin, bins where either side is missing (e.g. `NaN`) are left out.
+        let (first, second): (Vec<&Bar>, Vec<&Bar>) = bars.iter().partition(|bar| bar.category_index == 0);
+        for a in first {
+            let Some(b) = second.iter().find(|b| b.bar_index == a.bar_index) else {
+                continue;
+            };
+            // Both dots go in the middle of the bin, which is right between the two bars.
+            let bin_pos = (a.pos + b.pos + b.width) / 2.0;
+            let (x1, y1) = self.place_point(bin_pos, value_scale.pos(a.tip));
+            let (x2, y2) = self.place_point(bin_pos, value_scale.pos(b.tip));
+            self.nodes.push(tag::line(x1, x2, y1, y2, line_color, width));
+
+            let radius = self.get_dot_radius(a.width * 2.0);
+            self.nodes.push(tag::circle(x1, y1, radius, bar_color(bar_colors, bar_values, a)));
+            self.nodes.push(tag::circle(x2, y2, radius, bar_color(bar_colors, bar_values, b)));
+        }
+    }
+
+    // Dots are never wider than the space given to them, but never thinner than a line either.
+    fn get_dot_radius(&self, available_width: f64) -> f64 {
+        let base = self.get_base_line_width();
+        (available_width / 2.0).min(base * 0.6).max(base / 10.0)
+    }
+
+    fn generate_baseline(&mut self, baseline: f64, value_scale: &ValueScale, color: &str) {
+        let width = self.get_base_line_width() / 5.0;
+        let value_pos = value_scale.pos(baseline);
+        if self.radial {
+            let (cx, cy, _) = self.get_radial_frame();
+            let (_, radius) = self.place_polar(0.0, value_pos);
+            self.nodes.push(tag::ring(cx, cy, radius, color, width));
+            return;
+        }
+        let (x1, y1) = self.place_point(0.0, value_pos);
+        let (x2, y2) = self.place_point(self.get_bin_axis_length(), value_pos);
+        self.nodes.push(tag::line(x1, x2, y1, y2, color, width));
+    }
+
+    fn generate_wicks(&mut self, bars: &[Bar], value_scale: &ValueScale, bar_values: &BarValues, bar_colors: &BarColors) {
+        let width = self.get_base_line_width() / 5.0;
+        for bar in bars {
+            let Some((low, high)) = &bar_values.wicks[bar.category_index] else {
+                continue;
+            };
+            let bar_color = bar_color(bar_colors, bar_values, bar);
+            let bin_pos = bar.pos + (bar.width / 2.0);
+
+            let (x1, y1) = self.place_point(bin_pos, value_scale.pos(low[bar.bar_index]));
+            let (x2, y2) = self.place_point(bin_pos, value_scale.pos(high[bar.bar_index]));
+            self.nodes.push(tag::line(x1, x2, y1, y2, bar_color, width));
+        }
+    }
+
+    fn generate_box_plots(
+        &mut self,
+        bars: &[Bar],
+        value_scale: &ValueScale,
+        box_plots: &[(usize, &BoxPlot)],
+        bar_values: &BarValues,
+        bar_colors: &BarColors,
+        median_color: &str,
+    ) {
+        let width = self.get_base_line_width() / 5.0;
+        for bar in bars {
+            for (category_index, box_plot) in box_plots {
+                if *category_index != bar.category_index {
+                    continue;
+                }
+
+                let bar_color = bar_color(bar_colors, bar_values, bar);
+                let bin_pos = bar.pos + (bar.width / 2.0);
+
+                let value_pos = value_scale.pos(box_plot.medians()[bar.bar_index]);
+                let (x1, y1) = self.place_point(bar.pos, value_pos);
+                let (x2, y2) = self.place_point(bar.pos + bar.width, value_pos);
+                self.nodes.push(tag::line(x1, x2, y1, y2, median_color, width * 2.0));
+
+                // Caps at the end of the whiskers, half as wide as the box.
+                let half_cap = bar.width / 4.0;
+                for whisker in [box_plot.lower_whiskers()[bar.bar_index], box_plot.upper_whiskers()[bar.bar_index]] {
+                    let value_pos = value_scale.pos(whisker);
+                    let (x1, y1) = self.place_point(bin_pos - half_cap, value_pos);
+                    let (x2, y2) = self.place_point(bin_pos + half_cap, value_pos);
+                    self.nodes.push(tag::line(x1, x2, y1, y2, bar_color, width));
+                }
+
+                let radius = self.get_dot_radius(bar.width) / 2.0;
+                for outlier in box_plot.outliers()[bar.bar_index].iter() {
+                    let (x, y) = self.place_point(bin_pos, value_scale.pos(*outlier));
+                    self.nodes.push(tag::circle(x, y, radius, bar_color));
+                }
+            }
+        }
+    }
+
+    fn generate_min_max_dots(&mut self, bars: &[Bar], value_scale: &ValueScale, radius: f64, color: &str) {
+        let first_category = bars.iter().filter(|bar| bar.category_index == 0);
+        let min_bar = first_category.clone().min_by(|a, b| a.value.total_cmp(&b.value));
+        let max_bar = first_category.max_by(|a, b| a.value.total_cmp(&b.value));
+
+        for bar in min_bar.into_iter().chain(max_bar) {
+            let (x, y) = self.place_point(bar.pos + (bar.width / 2.0), value_scale.pos(bar.tip));
+            self.nodes.push(tag::circle(x, y, radius, color));
+        }
     }
 
-    fn get_base_line_width(&self) -> f64 {
-        (self.get_svg_width() * self.get_svg_height()).sqrt() / 100.0
+    fn generate_bullet_targets(&mut self, bars: &[Bar], value_scale: &ValueScale, targets: &[f64], color: &str) {
+        let width = self.get_base_line_width() / 2.0;
+        for bar in bars {
+            // The mark goes across the bar, reaching most of the way over the ranges behind it.
+            let range_width = bar.width / BULLET_BAR_WIDTH;
+            let half_length = range_width * BULLET_TARGET_WIDTH / 2.0;
+            let bin_center = bar.pos + (bar.width / 2.0);
+
+            let value_pos = value_scale.pos(targets[bar.bar_index]);
+            let (x1, y1) = self.place_point(bin_center - half_length, value_pos);
+            let (x2, y2) = self.place_point(bin_center + half_length, value_pos);
+            self.nodes.push(tag::line(x1, x2, y1, y2, color, width));
+        }
     }
 
-    fn set_background_color(&mut self, color: &str) {
-        let (width, height) = (self.get_svg_width(), self.get_svg_height());
-        let rect = tag::rect(0.0, 0.0, width, height, 1.0, color);
-        self.nodes.push(rect);
+    fn generate_waterfall_connectors(&mut self, bars: &[Bar], value_scale: &ValueScale, color: &str) {
+        let width = self.get_base_line_width() / 10.0;
+        // Join the running total at the end of each bar with the next bar.
+        for pair in bars.windows(2) {
+            let (bar, next_bar) = (&pair[0], &pair[1]);
+            let value_pos = value_scale.pos(bar.tip);
+            let (x1, y1) = self.place_point(bar.pos + bar.width, value_pos);
+            let (x2, y2) = self.place_point(next_bar.pos, value_pos);
+            self.nodes.push(tag::line(x1, x2, y1, y2, color, width));
+        }
     }
 
-    fn set_plot_window(&mut self, x_size: Percentage, x_offset: Percentage, y_size: Percentage, y_offset: Percentage) {
-        // Calculate the plot window size and offset from given percentage.
-        assert!(x_size <= 100.0 && x_offset <= 100.0, "x_size and or x_offset cannot exceed 100%");
-        assert!(y_size <= 100.0 && y_offset <= 100.0, "y_size and or y_offset cannot exceed 100%");
+    fn generate_error_bars(
+        &mut self,
+        bars: &[Bar],
+        value_scale: &ValueScale,
+        error_bars: &[(usize, ErrorBars)],
+        cap_width: Percentage,
+        line_width: Percentage,
+        color: &str,
+    ) {
+        let width = self.get_base_line_width() / 5.0 * (line_width / 100.0);
+        for bar in bars {
+            for (category_index, errors) in error_bars {
+                if *category_index != bar.category_index {
+                    continue;
+                }
 
-        let x_length = self.get_svg_width() * x_size / 100.0;
-        let x_offset = ((self.get_svg_width() * (1.0 - x_size / 100.0)) / 100.0) * x_offset;
+                // Errors are measured from the value, but drawn from the tip of the bar (which differs for stacks).
+                let (lower, upper) = match errors {
+                    ErrorBars::Symmetric(errors) => (bar.tip - errors[bar.bar_index], bar.tip + errors[bar.bar_index]),
+                    ErrorBars::Bounds(lower, upper) => {
+                        let shift = bar.tip - bar.value;
+                        (lower[bar.bar_index] + shift, upper[bar.bar_index] + shift)
+                    }
+                };
+                let (lower, upper) = (value_scale.pos(lower), value_scale.pos(upper));
 
-        let y_length = self.get_svg_height() * y_size / 100.0;
-        let y_offset = ((self.get_svg_height() * (1.0 - y_size / 100.0)) / 100.0) * y_offset;
+                let center = bar.pos + (bar.width / 2.0);
+                let cap = bar.width * (cap_width / 100.0) / 2.0;
 
-        let (x1, x2, y1, y2) = (x_offset, x_offset + x_length, y_offset, y_length + y_offset);
+                let (x1, y1) = self.place_point(center, lower);
+                let (x2, y2) = self.place_point(center, upper);
+                self.nodes.push(tag::line(x1, x2, y1, y2, color, width));
 
-        self.plot_window = Some((x1, x2, y1, y2));
+                for value_pos in [lower, upper] {
+                    let (x1, y1) = self.place_point(center - cap, value_pos);
+                    let (x2, y2) = self.place_point(center + cap, value_pos);
+                    self.nodes.push(tag::line(x1, x2, y1, y2, color, width));
+                }
+            }
+        }
     }
 
-    #[allow(clippy::too_many_arguments)]
-    fn generate_scale_range(
+    fn generate_stack_totals(
         &mut self,
-        min: i64,
-        max: i64,
-        step: usize,
-        axis_offset: Percentage,
-        show_horizontal_lines: bool,
-        line_color: &str,
-        tick_color: &str,
+        bars: &[Bar],
+        bar_values: &BarValues,
+        value_scale: &ValueScale,
         text_color: &str,
         font_size: Percentage,
     ) {
-        let (x1, x2, _, y2) = self.plot_window.unwrap_or(self.svg_window);
-        let x3 = (x1 / 100.0) * (100.0 - axis_offset); // tick left end
-        let range = max as f64 - min as f64;
-        let vertical_move = self.get_plot_height() / range;
-        let line_width = self.get_base_line_width() / 10.0;
         let font_size = self.get_font_size(font_size);
         let font_fraction = font_size / 3.5;
-        for n in (min..=max).step_by(step) {
-            let cur_y = if min < 0 {
-                y2 - ((n as f64 * vertical_move) - (min as f64 * self.get_plot_height() / range))
+
+        // Each stack is labeled once, centered on the first bar found in it.
+        let mut labeled: Vec<(usize, usize)> = Vec::with_capacity(bar_values.len());
+        for bar in bars {
+            let group = bar_values.groups[bar.category_index];
+            if labeled.contains(&(bar.bar_index, group)) {
+                continue;
+            }
+            labeled.push((bar.bar_index, group));
+
+            let (negative_end, positive_end) = bar_values.bin_stack(bar.bar_index, group);
+            let total = negative_end + positive_end;
+            let stack_center = bar.pos + (bar.width / 2.0);
+
+            // Put the label just beyond the end of the stack, on the side the total is pointing.
+            let text = &format_number(total);
+            let tag = if total >= 0.0 {
+                let (x, y) = self.place_point(stack_center, value_scale.pos(positive_end));
+                if self.horizontal {
+                    tag::text(x + font_fraction, y + font_fraction, text_color, font_size, "start", text)
+                } else {
+                    tag::text(x, y - font_fraction, text_color, font_size, "middle", text)
+                }
             } else {
-                y2 - (n as f64 * vertical_move)
+                let (x, y) = self.place_point(stack_center, value_scale.pos(negative_end));
+                if self.horizontal {
+                    tag::text(x - font_fraction, y + font_fraction, text_color, font_size, "end", text)
+                } else {
+                    tag::text(x, y + font_size, text_color, font_size, "middle", text)
+                }
             };
+            self.nodes.push(tag);
+        }
+    }
 
-            if show_horizontal_lines {
-                let tag = tag::line(x1, x2, cur_y, cur_y, line_color, line_width);
-                self.nodes.push(tag);
-            }
+    fn generate_curves(&mut self, bar_values: &BarValues, value_scale: &ValueScale, curves: &[Curve]) {
+        let bin_scale = self.get_bin_scale(bar_values);
+        let width = self.get_base_line_width() / 5.0;
 
-            // If offset is 0, no point in rendering the tick.
-            if axis_offset != 0.0 {
-                let tag = tag::line(x3, x1, cur_y, cur_y, tick_color, line_width);
-                self.nodes.push(tag);
-            }
+        for curve in curves {
+            let points: Vec<(f64, f64)> = curve.points.iter().map(|(bin_pos, value)| {
+                self.place_point(bin_scale.pos(*bin_pos), value_scale.pos(*value))
+            }).collect();
 
-            let num = &n.to_string();
-            let tag = tag::text(x3 - font_fraction, cur_y + font_fraction, text_color, font_size, "end", num);
+            let tag = tag::curve(&points, curve.color, width);
             self.nodes.push(tag);
         }
     }
 
-    #[allow(clippy::too_many_arguments)]
-    fn generate_bin_markers(
-            &mut self,
-            bar_values: &BarValues,
-            markers: &[&str],
-            x_axis_tick_length: Percentage,
-            bin_marker_position: &BinMarkerPosition,
-            show_vertical_lines: bool,
-            colors: &Colors,
-            font_size: Percentage,
-        ) {
-        let (x1, _, y1, y2) = self.plot_window.unwrap_or(self.svg_window);
-        let y3 = y2 + ((self.get_svg_height() - y2) / 100.0 * x_axis_tick_length);
-
-        let line_width = self.get_base_line_width() / 10.0;
-        let font_size = self.get_font_size(font_size);
+    fn generate_line_series(&mut self, bar_values: &BarValues, value_scale: &ValueScale, line_series: &[LineSeries]) {
+        let bin_scale = self.get_bin_scale(bar_values);
+        let bin_width = self.get_bin_axis_length() / bar_values.len() as f64; // Average, if bins are uneven.
+        let base_width = self.get_base_line_width() / 5.0;
 
-        let scale_unit = self.get_plot_width() / bar_values.len() as f64;
-        let marker_shift = match bin_marker_position {
-            BinMarkerPosition::Middle => scale_unit / 2.0,
-            BinMarkerPosition::Left => 0.0,
-            BinMarkerPosition::Right => scale_unit,
-        };
+        for series in line_series {
+            let width = base_width * (series.width / 100.0);
+            // Dash and gap are given as a percentage of the bin width.
+            let dash = series.dash.map(|(dash, gap)| (bin_width / 100.0 * dash, bin_width / 100.0 * gap));
 
-        for i in 0..markers.len() {
-            let cur_x = x1 + (scale_unit * i as f64) + marker_shift;
+            // The line goes through the middle of each bin.
+            let points: Vec<(f64, f64)> = series.values.iter().enumerate().map(|(i, f)| {
+                self.place_point(bin_scale.center(i), value_scale.pos(*f))
+            }).collect();
 
-            if show_vertical_lines {
-                let tag = tag::line(cur_x, cur_x, y1, y2, colors.line, line_width);
-                self.nodes.push(tag);
-            }
+            let tag = tag::polyline(&points, series.color, width, dash);
+            self.nodes.push(tag);
 
-            // If offset is 0, no point in rendering the tick.
-            if x_axis_tick_length != 0. {
-                let tag = tag::line(cur_x, cur_x, y2, y3, colors.tick, line_width);
-                self.nodes.push(tag);
+            if series.point_markers {
+                for (x, y) in points {
+                    let tag = tag::circle(x, y, width * 2.0, series.color);
+                    self.nodes.push(tag);
+                }
             }
-
-            let text = &markers[i];
-            let tag = tag::text(cur_x, y3 + font_size, colors.text, font_size, "middle", text);
-            self.nodes.push(tag);
         }
     }
 
-    #[allow(clippy::too_many_arguments)]
-    fn generate_bars(
-        &mut self,
-        bar_values: &BarValues,
-        scale_range: Option<(i64, i64, usize)>,
-        negative_bars_go_down: bool,
-        bin_gap: Percentage,
-        bar_gap: Percentage,
-        bar_colors: &BarColors,
-    ) {
-        let (y_min, y_max) = match scale_range {
-            Some((min, max, _)) => (min as f64, max as f64),
-            None => (bar_values.min, bar_values.max),
-        };
-        let (x1, _, _, y2) = self.plot_window.unwrap_or(self.svg_window);
-
-        let range = y_max - y_min;
-        let top_offset = y_min * self.get_plot_height() / range;
-        let scale_unit = self.get_plot_height() / range;
+    fn generate_points(&mut self, bar_values: &BarValues, value_scale: &ValueScale, points: &[Points]) {
+        let bin_scale = self.get_bin_scale(bar_values);
+        let base_size = self.get_base_line_width() * 0.4;
 
-        let bin_width = self.get_plot_width() / bar_values.len() as f64;
-        let bin_margin = bin_width * (bin_gap / 100.0);
+        for group in points {
+            let size = base_size * (group.size / 100.0);
+            let mut jitter = Jitter::default();
 
-        let margined_bin_width = (bin_width - bin_margin) / bar_values.values.len() as f64;
-        let bar_margin = (bin_width - bin_margin) / bar_values.values.len() as f64 * (bar_gap / 100.0);
-        let bar_width = margined_bin_width - bar_margin;
-
-        let x3 = x1 + bin_margin - (bin_margin / 2.0) + (bar_margin / 2.0);
-        // FIXME: Let user set custom opacity.
-        let opacity = 1.0;
-        for (category_index, values) in bar_values.values.iter().enumerate() {
-            let x4 = x3 + (margined_bin_width * category_index as f64);
-            for (bar_index, bar_value) in values.iter().copied().enumerate() {
-                let bar_x = x4 + (bin_width * bar_index as f64);
-
-                // FIXME: Can this be written in a more compact and simple way?
-                let (bar_y, bar_height) = if negative_bars_go_down {
-                    if bar_value >= 0.0 {
-                        let bar_height = bar_value * scale_unit;
-                        let bar_y = y2 + top_offset - bar_height;
-                        (bar_y, bar_height)
-                    } else {
-                        // If negative bars go down, we need to adjust "y" and "height" accordingly.
-                        let bar_height = (bar_value * scale_unit).abs();
-                        let bar_y = y2 + top_offset;
-                        (bar_y, bar_height)
-                    }
-                } else {
-                    let bar_height = (bar_value * scale_unit) - top_offset;
-                    let bar_y = y2 - bar_height;
-                    (bar_y, bar_height)
-                };
+            for (bin, value) in group.bins.iter().copied().zip(group.values) {
+                let jitter_width = bin_scale.width(bin) / 100.0 * group.jitter;
+                let bin_pos = bin_scale.center(bin) + jitter.next_offset() * jitter_width;
+                let (x, y) = self.place_point(bin_pos, value_scale.pos(*value));
 
-                let mut bar_color = match &bar_colors.layout {
-                    BarColorLayout::Category(arr) => {
-                        arr[category_index]
+                match group.shape {
+                    PointShape::Circle => {
+                        self.nodes.push(tag::circle(x, y, size, group.color));
                     }
-                    BarColorLayout::Indexed(arr) => {
-                        arr[category_index][bar_index]
+                    PointShape::Square => {
+                        self.nodes.push(tag::rect(x - size, y - size, size * 2.0, size * 2.0, 1.0, group.color));
                     }
-                    BarColorLayout::Threshold((clr_min, clr_low, clr_high, clr_max)) => {
-                        if bar_value == bar_values.max { clr_max }
-                        else if bar_value == bar_values.min { clr_min }
-                        else if bar_value >= bar_values.mean { clr_high }
-                        else { clr_low }
+                    PointShape::Diamond => {
+                        let corners = [(x, y - size), (x + size, y), (x, y + size), (x - size, y)];
+                        self.nodes.push(tag::polygon(&corners, group.color));
                     }
-                    BarColorLayout::Uniform(color) => {
-                        color
-                    }
-                };
-
-                if !bar_colors.overrides.is_empty() {
-                    for (i, j, color) in bar_colors.overrides.iter().copied() {
-                        if category_index == i && bar_index == j {
-                            bar_color = color;
-                            break;
-                        }
+                    PointShape::Cross => {
+                        let width = size / 2.0;
+                        self.nodes.push(tag::line(x - size, x + size, y - size, y + size, group.color, width));
+                        self.nodes.push(tag::line(x - size, x + size, y + size, y - size, group.color, width));
                     }
                 }
-
-                let tag = tag::rect(bar_x, bar_y, bar_width, bar_height, opacity, bar_color);
-                self.nodes.push(tag);
             }
         }
     }
 
+    fn generate_pareto_line(&mut self, bar_values: &BarValues, color: &str) {
+        let bin_scale = self.get_bin_scale(bar_values);
+        let scale_unit = self.get_value_axis_length() / 100.0;
+        let width = self.get_base_line_width() / 5.0;
+
+        // The line goes through the middle of each bin, measured against the secondary percentage axis.
+        let points: Vec<(f64, f64)> = bar_values.cumulative_shares().iter().enumerate().map(|(i, share)| {
+            self.place_point(bin_scale.center(i), share * scale_unit)
+        }).collect();
+
+        let tag = tag::polyline(&points, color, width, None);
+        self.nodes.push(tag);
+    }
+
     fn generate_text(&mut self, text: &str, side: Side, offset: Percentage, color: &str, font_size: Percentage) {
         let (x1, x2, y1, y2) = self.plot_window.unwrap_or(self.svg_window);
 
@@ -334,6 +1414,8 @@ impl SvgGenerator {
     ) {
         let colors: &[&str] = match bar_colors.layout {
             BarColorLayout::Category(ref colors) => colors,
+            BarColorLayout::Waterfall((increase, decrease, total)) => &[increase, decrease, total],
+            BarColorLayout::Baseline((above, below)) | BarColorLayout::Candlestick((above, below)) => &[above, below],
             _ => &vec![DEFAULT_BAR_COLOR; categories.len()],
         };
 
@@ -370,7 +1452,7 @@ impl SvgGenerator {
         self.nodes.push(tag::line(x1, x2, y2, y2, color, width)); // bottom
     }
 
-    fn generate_lines_at(&mut self, lines_at: &Vec<LinesAt>) {
+    fn generate_lines_at(&mut self, lines_at: &Vec<LinesAt>, value_scale: &ValueScale) {
         let width = self.get_base_line_width() / 5.0;
         let base_x = self.get_plot_width() / 100.0;
         let base_y = self.get_plot_height() / 100.0;
@@ -385,6 +1467,11 @@ impl SvgGenerator {
                     let x = f * base_x + x1;
                     tag::line(x, x, y1, y2, color, width)
                 },
+                LinesAt::Value(f, color) => {
+                    // Drawn just like a baseline, across the plot at the value.
+                    self.generate_baseline(*f, value_scale, color);
+                    continue;
+                },
             };
             self.nodes.push(tag);
         }
@@ -418,43 +1505,282 @@ impl SvgGenerator {
 
         svg
     }
+
+    // Nested svg elements bring their own coordinate system, so the nodes need no offset of their own.
+    fn generate_panel(&self, x: f64, y: f64) -> String {
+        let mut svg = String::with_capacity(200*200);
+
+        svg.push_str(
+            format!(
+                r#"<svg x="{x}" y="{y}" width="{width}" height="{height}">"#,
+                width = self.get_svg_width(),
+                height = self.get_svg_height(),
+            ).as_str()
+        );
+        svg.push('\n');
+
+        svg.push_str(self.nodes.concat().as_str());
+
+        svg.push_str("</svg>\n");
+
+        svg
+    }
+}
+
+fn plot_bar_values(bp: &BarPlot) -> BarValues {
+    let mut bar_values = match bp.layout.bar_mode {
+        BarMode::StackedPercent => BarValues::from_shares(&bp.values, &bp.stack_groups),
+        BarMode::Waterfall => BarValues::from_waterfall(bp.values[0], bp.waterfall_totals.unwrap_or_default()),
+        _ => BarValues::from(&bp.values)
+            .with_starts(&bp.range_starts)
+            .with_wicks(&bp.wicks)
+            .with_groups(&bp.stack_groups),
+    };
+    bar_values.edges = bp.layout.bin_edges.clone();
+    bar_values
+}
+
+// Values of curves, line series, points, bullet ranges and targets, outliers and the baseline, which the scale must make room for as well.
+fn overlay_values<'a>(bp: &'a BarPlot) -> impl Iterator<Item = f64> + 'a {
+    let curve_values = bp.curves.iter().flat_map(|curve| curve.points.iter().map(|(_, f)| *f));
+    let line_series_values = bp.line_series.iter().flat_map(|series| series.values.iter().copied());
+    let point_values = bp.points.iter().flat_map(|points| points.values.iter().copied());
+    let bullet_values = bp.bullet_ranges.iter().flat_map(|(values, _)| values.iter().copied());
+    let target_values = bp.bullet_targets.iter().flat_map(|targets| targets.iter().copied());
+    let outlier_values = bp.box_plots.iter().flat_map(|(_, box_plot)| box_plot.outliers().iter().flatten().copied());
+    curve_values.chain(line_series_values).chain(point_values)
+        .chain(bullet_values).chain(target_values).chain(outlier_values).chain(bp.layout.baseline)
+}
+
+// The lowest and highest value that a plot would fit its scale around.
+pub fn value_extent(bp: &BarPlot) -> (f64, f64) {
+    plot_bar_values(bp).value_extent(&bp.layout.bar_mode, overlay_values(bp))
 }
 
 pub fn render(bp: &BarPlot) -> String {
+    draw(bp).generate_svg()
+}
+
+// Render a plot as a nested svg, placed at `x` and `y` within a larger svg.
+pub fn render_panel(bp: &BarPlot, x: f64, y: f64) -> String {
+    draw(bp).generate_panel(x, y)
+}
+
+// Only the bars (and optionally a dot on the lowest and highest value), filling the whole svg.
+pub fn render_sparkline(bp: &BarPlot) -> String {
+    let (svg_width, svg_height) = (bp.size.0 as f64, bp.size.1 as f64);
+
+    let mut svg = SvgGenerator::new(svg_width, svg_height);
+
+    let bar_values = plot_bar_values(bp);
+
+    // Line widths and font sizes follow the svg size, which is far too small here, so dots follow the height.
+    let dot_radius = svg_height.min(svg_width) / 10.0;
+    if bp.colors.sparkline_min_max.is_some() {
+        // Leave room for the dots at the ends of the bars, so they are not cut off at the edges.
+        svg.plot_window = Some((dot_radius, svg_width - dot_radius, dot_radius, svg_height - dot_radius));
+    }
+
+    svg.set_orientation(&bp.layout.orientation);
+
+    let scale_range = bp.layout.scale_range.unwrap_or_else(|| {
+        let (min, max) = bar_values.value_range(None, &bp.layout.bar_mode);
+        let (min, max) = (min.min(0.0), max.max(0.0));
+        // A flat trend of zeros still needs some height to scale against.
+        (min, if max > min { max } else { min + 1.0 }, 1.0)
+    });
+    let value_scale = svg.get_value_scale(&bar_values, Some(scale_range), &bp.layout.bar_mode);
+
+    if let Some(color) = bp.colors.background {
+        svg.set_background_color(color);
+    }
+
+    let baseline = bp.layout.baseline.or(bp.layout.negative_bars_go_down.then_some(0.0));
+    let bars = svg.layout_bars(
+        &bar_values,
+        &value_scale,
+        baseline,
+        &bp.layout.bar_mode,
+        bp.layout.bin_gap,
+        bp.layout.bar_gap,
+    );
+
+    let mut bar_colors = bp.colors.bars.clone();
+    if let Some(color) = bp.colors.sparkline_last_bar {
+        let last = bar_values.len() - 1;
+        for category_index in 0..bar_values.values.len() {
+            bar_colors.overrides.push((category_index, last, color));
+        }
+    }
+    svg.generate_bars(&bars, &value_scale, &bar_values, &bar_colors);
+
+    if let Some(color) = bp.colors.sparkline_min_max {
+        svg.generate_min_max_dots(&bars, &value_scale, dot_radius, color);
+    }
+
+    svg.generate_svg()
+}
+
+pub fn render_figure(
+    size: (u32, u32),
+    panels: &[String],
+    background: Option<&str>,
+    legend: Option<(&[&str], (Percentage, Percentage))>,
+    legend_plot: &BarPlot,
+) -> String {
+    let mut svg = SvgGenerator::new(size.0 as f64, size.1 as f64);
+
+    if let Some(color) = background {
+        svg.set_background_color(color);
+    }
+
+    svg.nodes.extend_from_slice(panels);
+
+    if let Some((categories, (x, y))) = legend {
+        svg.generate_legend(
+            categories,
+            x,
+            y,
+            legend_plot.colors.text,
+            legend_plot.layout.font_size,
+            &legend_plot.colors.bars,
+        );
+    }
+
+    svg.generate_svg()
+}
+
+fn draw(bp: &BarPlot) -> SvgGenerator {
     let (svg_width, svg_height) = (bp.size.0 as f64, bp.size.1 as f64);
 
     let mut svg = SvgGenerator::new(svg_width, svg_height);
 
-    let bar_values = BarValues::from(&bp.values);
+    let mut bar_values = plot_bar_values(bp);
+
+    // Pareto charts show the largest bins first, so the bin markers and bar colors must follow their bins.
+    // Bins without a marker are left blank, just like when bins are not sorted.
+    let (mut markers, bar_colors): (Option<Vec<&str>>, BarColors) = match bp.layout.bar_mode {
+        BarMode::Pareto => {
+            let order = bar_values.sort_descending();
+            let markers = bp.markers.as_ref().map(|markers| {
+                order.iter().map(|i| markers.get(*i).copied().unwrap_or("")).collect()
+            });
+            (markers, sorted_bar_colors(&bp.colors.bars, &order))
+        }
+        _ => (bp.markers.clone(), bp.colors.bars.clone()),
+    };
+
+    // Without bin markers, bins of different widths are marked by their edges.
+    let edge_markers: Vec<String> = bp.layout.bin_edges.iter().flatten().copied().map(format_number).collect();
+    let mut bin_marker_position = &bp.layout.bin_marker_position;
+    if markers.is_none() && !edge_markers.is_empty() {
+        markers = Some(edge_markers.iter().map(|s| s.as_str()).collect());
+        bin_marker_position = &BinMarkerPosition::Left;
+    }
+
+    // Both sides of a pyramid share the same scale (max, step), mirrored around the middle with room for markers.
+    let pyramid_scale = match bp.layout.bar_mode {
+        BarMode::Pyramid => {
+            let (_, max, step) = match bp.layout.scale_range {
+                Some(scale_range) => scale_range,
+                None => bar_values.nice_scale_range(&bp.layout.bar_mode, std::iter::empty()),
+            };
+            // The gap is a share of the value axis, so find how many values it spans.
+            let gap = bp.layout.pyramid_gap.unwrap_or(0.0) / 100.0;
+            let offset = max * gap / (1.0 - gap);
+            bar_values = BarValues::from_pyramid(&bp.values, offset);
+            bar_values.edges = bp.layout.bin_edges.clone();
+            Some((max, step, offset))
+        }
+        _ => None,
+    };
+
+    // Percentage stacks always fill the plot window, so they bring their own scale.
+    let (scale_range, scale_unit) = match bp.layout.bar_mode {
+        BarMode::Pyramid => {
+            let (max, step, offset) = pyramid_scale.unwrap();
+            (Some((-(max + offset), max + offset, step)), "")
+        }
+        BarMode::StackedPercent => {
+            let step = bp.layout.scale_range.map_or(DEFAULT_PERCENT_STEP, |(_, _, step)| step);
+            (Some((0.0, 100.0, step)), "%")
+        }
+        _ if bp.layout.scale_range_auto && bp.layout.time_axis => {
+            (Some(nice_time_scale(bar_values.value_extent(&bp.layout.bar_mode, overlay_values(bp)))), "")
+        }
+        _ if bp.layout.scale_range_auto => {
+            (Some(bar_values.nice_scale_range(&bp.layout.bar_mode, overlay_values(bp))), "")
+        }
+        _ => (bp.layout.scale_range, ""),
+    };
 
     if let Some((x_size, x_offset, y_size, y_offset)) = bp.layout.plot_window_scale {
         svg.set_plot_window(x_size, x_offset, y_size, y_offset);
     }
 
+    svg.set_orientation(&bp.layout.orientation);
+    svg.radial_rotation = bp.layout.radial_rotation.to_radians();
+    svg.time_axis = bp.layout.time_axis;
+
+    let value_scale = svg.get_value_scale(&bar_values, scale_range, &bp.layout.bar_mode);
+
+    // Bin markers and scale swap axis when bars are horizontal, so do their ticks and grid lines.
+    let (bin_tick_length, scale_tick_length, bin_grid_lines, scale_grid_lines) = match bp.layout.orientation {
+        BarOrientation::Vertical | BarOrientation::Radial => (
+            bp.layout.x_axis_tick_length,
+            bp.layout.y_axis_tick_length,
+            bp.show.vertical_lines,
+            bp.show.horizontal_lines,
+        ),
+        BarOrientation::Horizontal => (
+            bp.layout.y_axis_tick_length,
+            bp.layout.x_axis_tick_length,
+            bp.show.horizontal_lines,
+            bp.show.vertical_lines,
+        ),
+    };
+
     if let Some(color) = bp.colors.background {
         svg.set_background_color(color);
     }
 
-    if let Some(markers) = bp.markers {
+    if let (Some(markers), Some(_)) = (&markers, bp.layout.pyramid_gap) {
+        assert!(!markers.is_empty());
+        svg.generate_pyramid_markers(&bar_values, markers, bp.colors.text, bp.layout.font_size);
+    } else if let Some(markers) = &markers {
         assert!(!markers.is_empty());
         svg.generate_bin_markers(
             &bar_values,
             markers,
-            bp.layout.x_axis_tick_length,
-            &bp.layout.bin_marker_position,
-            bp.show.vertical_lines,
+            bin_tick_length,
+            bin_marker_position,
+            bin_grid_lines,
             &bp.colors,
             bp.layout.font_size,
         );
     }
 
-    if let Some((min, max, step)) = bp.layout.scale_range {
+    if let Some((max, step, offset)) = pyramid_scale {
+        svg.generate_pyramid_scale(
+            max,
+            step,
+            offset,
+            &value_scale,
+            scale_tick_length,
+            scale_grid_lines,
+            bp.colors.line,
+            bp.colors.tick,
+            bp.colors.text,
+            bp.layout.font_size,
+        );
+    } else if let Some((min, max, step)) = scale_range {
         svg.generate_scale_range(
             min,
             max,
             step,
-            bp.layout.y_axis_tick_length,
-            bp.show.horizontal_lines,
+            scale_unit,
+            scale_tick_length,
+            scale_grid_lines,
             bp.colors.line,
             bp.colors.tick,
             bp.colors.text,
@@ -462,6 +1788,16 @@ pub fn render(bp: &BarPlot) -> String {
         );
     }
 
+    if let BarMode::Pareto = bp.layout.bar_mode {
+        svg.generate_secondary_scale(
+            DEFAULT_PERCENT_STEP,
+            scale_tick_length,
+            bp.colors.tick,
+            bp.colors.text,
+            bp.layout.font_size,
+        );
+    }
+
     if let Some(text) = bp.plot_text.left {
         let offset = bp.plot_text.left_offset.unwrap_or(DEFAULT_TEXT_SIDE_OFFSET);
         svg.generate_text(text, Side::Left, offset, bp.colors.text, bp.layout.font_size);
@@ -486,16 +1822,114 @@ pub fn render(bp: &BarPlot) -> String {
         svg.generate_svg_border(bp.colors.line);
     }
 
-    svg.generate_bars(
+    // Negative bars going down is the same as bars growing out from a baseline at zero.
+    let baseline = bp.layout.baseline.or(bp.layout.negative_bars_go_down.then_some(0.0));
+
+    if let BarMode::Bullet = bp.layout.bar_mode {
+        let ranges: Vec<&[f64]> = bp.bullet_ranges.iter().map(|(values, _)| *values).collect();
+        let mut range_values = BarValues::from_bullet_ranges(&ranges, value_scale.min);
+        range_values.edges = bp.layout.bin_edges.clone();
+
+        let range_bars = svg.layout_bars(
+            &range_values,
+            &value_scale,
+            None,
+            &bp.layout.bar_mode,
+            bp.layout.bin_gap,
+            bp.layout.bar_gap,
+        );
+        let range_colors = BarColors {
+            layout: BarColorLayout::Category(bp.bullet_ranges.iter().map(|(_, color)| *color).collect()),
+            overrides: Vec::new(),
+        };
+        svg.generate_bars(&range_bars, &value_scale, &range_values, &range_colors);
+    }
+
+    let mut bars = svg.layout_bars(
         &bar_values,
-        bp.layout.scale_range,
-        bp.layout.negative_bars_go_down,
+        &value_scale,
+        baseline,
+        &bp.layout.bar_mode,
         bp.layout.bin_gap,
         bp.layout.bar_gap,
-        &bp.colors.bars,
     );
 
-    if let Some(categories) = bp.legend.categories {
+    // The bar of a bullet chart is narrower than its ranges, and centered in front of them.
+    if let BarMode::Bullet = bp.layout.bar_mode {
+        for bar in bars.iter_mut() {
+            bar.pos += bar.width * (1.0 - BULLET_BAR_WIDTH) / 2.0;
+            bar.width *= BULLET_BAR_WIDTH;
+        }
+    }
+
+    // Wicks go behind the bodies of the candlesticks.
+    if bar_values.wicks.iter().any(|wick| wick.is_some()) {
+        svg.generate_wicks(&bars, &value_scale, &bar_values, &bar_colors);
+    }
+
+    match bp.layout.bar_style {
+        BarStyle::Rectangle => svg.generate_bars(&bars, &value_scale, &bar_values, &bar_colors),
+        BarStyle::Lollipop => svg.generate_lollipops(&bars, &value_scale, &bar_values, &bar_colors),
+        BarStyle::Dumbbell => {
+            svg.generate_dumbbells(&bars, &value_scale, &bar_values, &bar_colors, bp.colors.line);
+        }
+    }
+
+    if let Some(baseline) = bp.layout.baseline {
+        svg.generate_baseline(baseline, &value_scale, bp.colors.line);
+    }
+
+    if !bp.box_plots.is_empty() {
+        svg.generate_box_plots(
+            &bars,
+            &value_scale,
+            &bp.box_plots,
+            &bar_values,
+            &bar_colors,
+            bp.colors.line,
+        );
+    }
+
+    if let (BarMode::Bullet, Some(targets)) = (&bp.layout.bar_mode, bp.bullet_targets) {
+        svg.generate_bullet_targets(&bars, &value_scale, targets, bp.colors.bullet_target);
+    }
+
+    if bp.show.waterfall_connectors {
+        svg.generate_waterfall_connectors(&bars, &value_scale, bp.colors.line);
+    }
+
+    if !bp.error_bars.is_empty() {
+        svg.generate_error_bars(
+            &bars,
+            &value_scale,
+            &bp.error_bars,
+            bp.layout.error_bar_cap_width,
+            bp.layout.error_bar_line_width,
+            bp.colors.error_bars,
+        );
+    }
+
+    if bp.show.stack_totals {
+        svg.generate_stack_totals(&bars, &bar_values, &value_scale, bp.colors.text, bp.layout.font_size);
+    }
+
+    if !bp.curves.is_empty() {
+        svg.generate_curves(&bar_values, &value_scale, &bp.curves);
+    }
+
+    if !bp.points.is_empty() {
+        svg.generate_points(&bar_values, &value_scale, &bp.points);
+    }
+
+    if !bp.line_series.is_empty() {
+        svg.generate_line_series(&bar_values, &value_scale, &bp.line_series);
+    }
+
+    if let BarMode::Pareto = bp.layout.bar_mode {
+        svg.generate_pareto_line(&bar_values, bp.colors.pareto_line);
+    }
+
+    if let Some(categories) = &bp.legend.categories {
         let (x, y) = bp.legend.position.unwrap_or(DEFAULT_LEGEND_POSITION);
         svg.generate_legend(
             categories,
@@ -512,8 +1946,8 @@ pub fn render(bp: &BarPlot) -> String {
     }
 
     if !bp.lines_at.is_empty() {
-        svg.generate_lines_at(&bp.lines_at);
+        svg.generate_lines_at(&bp.lines_at, &value_scale);
     }
 
-    svg.generate_svg()
+    svg
 }
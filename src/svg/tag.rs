@@ -10,6 +10,79 @@ pub fn line(x1: f64, x2: f64, y1: f64, y2: f64, color: &str, width: f64) -> Stri
     )
 }
 
+pub fn curve(points: &[(f64, f64)], color: &str, width: f64) -> String {
+    // Smooth curve through all points, made from cubic bezier segments (Catmull-Rom spline).
+    let mut d = String::with_capacity(points.len() * 64);
+    let (x, y) = points[0];
+    d.push_str(&format!("M {:.3} {:.3}", x, y));
+
+    let last = points.len() - 1;
+    for i in 0..last {
+        let (x0, y0) = points[i.saturating_sub(1)];
+        let (x1, y1) = points[i];
+        let (x2, y2) = points[i + 1];
+        let (x3, y3) = points[(i + 2).min(last)];
+
+        let (c1x, c1y) = (x1 + (x2 - x0) / 6.0, y1 + (y2 - y0) / 6.0);
+        let (c2x, c2y) = (x2 - (x3 - x1) / 6.0, y2 - (y3 - y1) / 6.0);
+        d.push_str(&format!(" C {:.3} {:.3}, {:.3} {:.3}, {:.3} {:.3}", c1x, c1y, c2x, c2y, x2, y2));
+    }
+
+    format!(
+        r#"<path d="{}" fill="none" stroke="{}" stroke-width="{:.3}" />{LF}"#,
+        d, color, width,
+    )
+}
+
+pub fn polyline(points: &[(f64, f64)], color: &str, width: f64, dash: Option<(f64, f64)>) -> String {
+    let points: Vec<String> = points.iter().map(|(x, y)| format!("{:.3},{:.3}", x, y)).collect();
+    let dash = match dash {
+        Some((dash, gap)) => format!(r#" stroke-dasharray="{:.3} {:.3}""#, dash, gap),
+        None => String::new(),
+    };
+    format!(
+        r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="{:.3}"{} />{LF}"#,
+        points.join(" "), color, width, dash,
+    )
+}
+
+pub fn circle(cx: f64, cy: f64, r: f64, color: &str) -> String {
+    format!(
+        r#"<circle cx="{:.3}" cy="{:.3}" r="{:.3}" fill="{}" />{LF}"#,
+        cx, cy, r, color,
+    )
+}
+
+pub fn ring(cx: f64, cy: f64, r: f64, color: &str, width: f64) -> String {
+    format!(
+        r#"<circle cx="{:.3}" cy="{:.3}" r="{:.3}" fill="none" stroke="{}" stroke-width="{:.3}" />{LF}"#,
+        cx, cy, r, color, width,
+    )
+}
+
+pub fn sector(cx: f64, cy: f64, r1: f64, r2: f64, a1: f64, a2: f64, color: &str) -> String {
+    // Angles are in radians, clockwise from straight up. The sector spans from radius r1 to r2.
+    let point = |r: f64, a: f64| (cx + r * a.sin(), cy - r * a.cos());
+    let large_arc = if a2 - a1 > std::f64::consts::PI { 1 } else { 0 };
+    let (x1, y1) = point(r2, a1);
+    let (x2, y2) = point(r2, a2);
+    let (x3, y3) = point(r1, a2);
+    let (x4, y4) = point(r1, a1);
+    let d = format!(
+        "M {:.3} {:.3} A {:.3} {:.3} 0 {} 1 {:.3} {:.3} L {:.3} {:.3} A {:.3} {:.3} 0 {} 0 {:.3} {:.3} Z",
+        x1, y1, r2, r2, large_arc, x2, y2, x3, y3, r1, r1, large_arc, x4, y4,
+    );
+    format!(r#"<path d="{}" fill="{}" />{LF}"#, d, color)
+}
+
+pub fn polygon(points: &[(f64, f64)], color: &str) -> String {
+    let points: Vec<String> = points.iter().map(|(x, y)| format!("{:.3},{:.3}", x, y)).collect();
+    format!(
+        r#"<polygon points="{}" fill="{}" />{LF}"#,
+        points.join(" "), color,
+    )
+}
+
 pub fn rect(x: f64, y: f64, width: f64, height: f64, opacity: f64, color: &str) -> String {
     format!(
         r#"<rect x="{:.3}" y="{:.3}" width="{:.3}" height="{:.3}" style="fill-opacity: {:.3}; fill: {};" />{LF}"#,
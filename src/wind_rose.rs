@@ -0,0 +1,155 @@
+use crate::svg::format_number;
+
+const COMPASS_4: [&str; 4] = ["N", "E", "S", "W"];
+const COMPASS_8: [&str; 8] = ["N", "NE", "E", "SE", "S", "SW", "W", "NW"];
+const COMPASS_16: [&str; 16] = [
+    "N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE",
+    "S", "SSW", "SW", "WSW", "W", "WNW", "NW", "NNW",
+];
+
+/// Wind samples sorted into direction sectors and speed classes, ready to be added to a [`crate::BarPlot`].
+///
+/// Each sample is a `(direction, speed)` pair, where the direction is in degrees the wind is coming from
+/// (0 is north, 90 is east). The first sector is centered on north, and the rest follow clockwise.
+///
+/// Each speed class includes its lower bound and excludes the lower bound of the next class,
+/// while the last class has no upper bound. Samples slower than the first class are counted as calm.
+/// Samples where either the direction or the speed is `NaN` are left out.
+///
+/// All values are given as a percentage of all samples (calm included), so the sectors sum up to 100% minus calm.
+///
+/// # Example
+///
+/// ```
+/// use eb_bars::{BarPlot, WindRose};
+///
+/// let samples = [(10.0, 3.5), (95.0, 7.2), (350.0, 2.8), (180.0, 0.2)];
+/// let speed_classes = [0.5, 2.0, 5.0];
+///
+/// let wind_rose = WindRose::new(&samples, 4, &speed_classes);
+///
+/// // The sample at 350° goes into the north sector, together with the one at 10°.
+/// assert_eq!(wind_rose.counts()[1], &[2.0, 0.0, 0.0, 0.0]);
+/// assert_eq!(wind_rose.calm(), 25.0);
+///
+/// let mut plot = BarPlot::new();
+/// plot.add_wind_rose(&wind_rose);
+///
+/// let svg: String = plot.to_svg(1600, 1000);
+/// ```
+#[derive(Debug)]
+pub struct WindRose {
+    counts: Vec<Vec<f64>>,
+    values: Vec<Vec<f64>>,
+    calm: f64,
+    markers: Vec<String>,
+    legend: Vec<String>,
+}
+
+impl WindRose {
+    /// Sort wind samples into `sectors` direction sectors and the given speed classes.
+    ///
+    /// The speed classes are given by their lower bound, e.g. `[0.5, 2.0, 5.0]` gives the classes
+    /// 0.5-2, 2-5 and 5+, where anything slower than 0.5 is calm.
+    ///
+    /// Sectors are named by the compass (N, NE, E..) when there are 4, 8 or 16 of them, otherwise by degrees.
+    ///
+    /// # Panics
+    ///
+    /// * If there are no samples (not counting `NaN`).
+    /// * If there are no sectors, or if the speed classes are empty or not increasing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eb_bars::WindRose;
+    ///
+    /// let samples = [(0.0, 4.0), (45.0, 6.0), (90.0, 12.0), (270.0, 8.0)];
+    ///
+    /// let wind_rose = WindRose::new(&samples, 8, &[0.0, 5.0, 10.0]);
+    ///
+    /// // Slowest class first, one value per sector.
+    /// assert_eq!(wind_rose.values()[0], &[25.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+    /// ```
+    pub fn new(samples: &[(f64, f64)], sectors: usize, speed_classes: &[f64]) -> Self {
+        assert!(sectors > 0, "A wind rose needs at least one sector.");
+        assert!(!speed_classes.is_empty(), "A wind rose needs at least one speed class.");
+        assert!(
+            speed_classes.windows(2).all(|pair| pair[0] < pair[1]),
+            "Speed classes must be strictly increasing.",
+        );
+
+        let samples: Vec<(f64, f64)> = samples
+            .iter()
+            .copied()
+            .filter(|(direction, speed)| !direction.is_nan() && !speed.is_nan())
+            .collect();
+        assert!(!samples.is_empty(), "Can not create a wind rose without any samples.");
+
+        // Sectors are centered on their direction, so the first one starts half a sector before north.
+        let sector_width = 360.0 / sectors as f64;
+        let mut counts = vec![vec![0.0; sectors]; speed_classes.len()];
+        let mut calm_count = 0.0;
+        for (direction, speed) in samples.iter().copied() {
+            if speed < speed_classes[0] {
+                calm_count += 1.0;
+                continue;
+            }
+            let class_index = speed_classes.partition_point(|class| *class <= speed) - 1;
+            let direction = (direction + (sector_width / 2.0)).rem_euclid(360.0);
+            let sector_index = ((direction / sector_width) as usize).min(sectors - 1);
+            counts[class_index][sector_index] += 1.0;
+        }
+
+        let n = samples.len() as f64;
+        let values = counts
+            .iter()
+            .map(|class| class.iter().map(|count| count / n * 100.0).collect())
+            .collect();
+
+        let markers = match sectors {
+            4 => COMPASS_4.iter().map(|s| s.to_string()).collect(),
+            8 => COMPASS_8.iter().map(|s| s.to_string()).collect(),
+            16 => COMPASS_16.iter().map(|s| s.to_string()).collect(),
+            _ => (0..sectors).map(|i| format!("{}°", format_number(sector_width * i as f64))).collect(),
+        };
+
+        let last = speed_classes.len() - 1;
+        let legend = speed_classes
+            .iter()
+            .enumerate()
+            .map(|(i, low)| {
+                if i == last {
+                    format!("{}+", format_number(*low))
+                } else {
+                    format!("{}-{}", format_number(*low), format_number(speed_classes[i + 1]))
+                }
+            })
+            .collect();
+
+        Self { counts, values, calm: calm_count / n * 100.0, markers, legend }
+    }
+
+    /// Number of samples in each sector, one set of sectors for each speed class (slowest first).
+    pub fn counts(&self) -> &[Vec<f64>] {
+        &self.counts
+    }
+
+    /// Percentage of all samples in each sector, one set of sectors for each speed class (slowest first).
+    pub fn values(&self) -> &[Vec<f64>] {
+        &self.values
+    }
+
+    /// Percentage of all samples that are calm, e.g. slower than the first speed class.
+    pub fn calm(&self) -> f64 {
+        self.calm
+    }
+
+    pub(crate) fn markers(&self) -> Vec<&str> {
+        self.markers.iter().map(|s| s.as_str()).collect()
+    }
+
+    pub(crate) fn legend(&self) -> Vec<&str> {
+        self.legend.iter().map(|s| s.as_str()).collect()
+    }
+}
@@ -1,10 +1,10 @@
 use std::path::Path;
 use std::fs;
 
-use chrono::{DateTime, Duration, Local, Timelike};
+use chrono::{DateTime, Duration, Local, NaiveDate, Timelike};
 use rand::Rng;
 
-use eb_bars::BarPlot;
+use eb_bars::{BarPlot, BoxPlot, BoxWhiskers, Figure, Histogram, HistogramBins, WindRose};
 
 #[test]
 fn bar_colors() {
@@ -38,7 +38,7 @@ fn bar_colors() {
     plot.set_text_right_offset(50.0); // Set offset from side to plot border to 50%
 
     let contents = plot.to_svg(1600, 1000);
-    if let Err(e) = fs::write(&output, contents) {
+    if let Err(e) = fs::write(output, contents) {
         eprintln!("Error saving '{}', {}", output.display(), e);
     }
 }
@@ -104,7 +104,7 @@ fn wind_forecast() {
         let seconds_passed = (dt_current - dt_start).num_seconds();
 
         let mut rng = rand::rng();
-        let mut dt_end = dt_start.clone();
+        let mut dt_end = dt_start;
         let mut hour_marks: Vec<String> = Vec::with_capacity(25);
         let mut wind_values: Vec<f64> = Vec::with_capacity(25);
 
@@ -211,7 +211,7 @@ fn random_values() {
     plot.set_bar_colors_by_threshold(min_color, low_color, high_color, max_color);
 
     let contents = plot.to_svg(1600, 1000);
-    if let Err(e) = std::fs::write(&output, contents) {
+    if let Err(e) = std::fs::write(output, contents) {
         eprintln!("Error saving '{}', {}", output.display(), e);
     }
 }
@@ -272,7 +272,1168 @@ fn fruit_picking() {
     plot.set_bin_gap(15.0);
 
     let contents = plot.to_svg(1600, 1000);
-    if let Err(e) = fs::write(&output, contents) {
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn lines_changed() {
+    let output = Path::new("lines_changed.test.svg");
+
+    let this_week = [1204., 873., 455., 312., 150.];
+    let last_week = [1103., 912., 301., 287., 201.];
+    let packages = [
+        "src/parser/expression.rs",
+        "src/parser/statement.rs",
+        "src/lexer.rs",
+        "src/codegen/emit.rs",
+        "src/main.rs",
+    ];
+
+    let mut plot = BarPlot::new();
+
+    plot.set_orientation_horizontal();
+
+    plot.add_values(&this_week);
+    plot.add_bar_colors_by_category("rgb(137, 174, 255)");
+    plot.add_values(&last_week);
+    plot.add_bar_colors_by_category("rgb(126, 255, 165)");
+
+    let categories = ["This week", "Last week"];
+    plot.set_legend(&categories);
+    plot.set_legend_position(88.0, 20.0);
+
+    plot.set_bin_markers(&packages);
+    plot.set_bin_markers_middle();
+    plot.set_scale_range(0, 1400, 200);
+    plot.set_show_vertical_lines();
+
+    // Mark the weekly target.
+    plot.add_vertical_line_at(1000.0 / 1400.0 * 100.0, "White");
+
+    plot.set_text_top("Lines changed per file, vertical line marks the weekly review limit");
+    plot.set_text_top_offset(40.0);
+    plot.set_text_bottom("Lines changed");
+    plot.set_text_bottom_offset(25.0);
+
+    plot.set_background_color("Black");
+    plot.set_plot_window_size(60.0, 80.0, 80.0, 40.0);
+    plot.set_show_plot_border();
+    plot.set_bin_gap(20.0);
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn fruit_picking_stacked() {
+    let output = Path::new("fruit_picking_stacked.test.svg");
+
+    let tomatoes = [29, 41, 64, 83, 59, 42, 65];
+    let apples = [9, 51, 67, 55, 11, 93, 43];
+    let eggplants = [18, 86, 13, 30, 1, 10, 58];
+    let weekdays = ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"];
+
+    let mut plot = BarPlot::new();
+
+    plot.set_bars_stacked();
+    plot.set_show_stack_totals();
+
+    let values_a = tomatoes.into_iter().map(|i| i as f64).collect::<Vec<f64>>();
+    plot.add_values(&values_a);
+    plot.add_bar_colors_by_category("Red");
+
+    let values_b = apples.into_iter().map(|i| i as f64).collect::<Vec<f64>>();
+    plot.add_values(&values_b);
+    plot.add_bar_colors_by_category("LawnGreen");
+
+    let values_c = eggplants.into_iter().map(|i| i as f64).collect::<Vec<f64>>();
+    plot.add_values(&values_c);
+    plot.add_bar_colors_by_category("Blue");
+
+    plot.set_bin_markers(&weekdays);
+
+    let categories = ["Tomatoes", "Apples", "Eggplants"];
+    plot.set_legend(&categories);
+    plot.set_legend_position(91.2, 22.2);
+
+    plot.set_text_top("Total harvested per weekday, split by category");
+    plot.set_text_top_offset(40.0);
+    plot.set_text_left("Total harvested.");
+    plot.set_text_left_offset(25.0);
+
+    plot.set_background_color("Black");
+    plot.set_plot_window_size(80.0, 35.0, 85.0, 50.0);
+    plot.set_scale_range(0, 200, 20);
+    plot.set_line_color("White");
+    plot.set_text_color("LightGoldenRodYellow");
+    plot.set_tick_color("LightGoldenRodYellow");
+    plot.set_bin_markers_middle();
+    plot.set_show_plot_border();
+    plot.set_show_horizontal_lines();
+    plot.set_bin_gap(30.0);
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn traffic_share() {
+    let output = Path::new("traffic_share.test.svg");
+
+    let desktop = [5310., 6102., 2004., 4150., 390., 8871., 4420.];
+    let mobile = [7211., 9940., 1733., 3012., 1205., 9013., 3987.];
+    let tablet = [811., 640., 402., 380., 87., 1302., 622.];
+    let weekdays = ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"];
+
+    let mut plot = BarPlot::new();
+
+    plot.set_bars_stacked_percent();
+
+    plot.add_values(&desktop);
+    plot.add_bar_colors_by_category("rgb(137, 174, 255)");
+    plot.add_values(&mobile);
+    plot.add_bar_colors_by_category("rgb(126, 255, 165)");
+    plot.add_values(&tablet);
+    plot.add_bar_colors_by_category("rgb(255, 233, 133)");
+
+    let categories = ["Desktop", "Mobile", "Tablet"];
+    plot.set_legend(&categories);
+    plot.set_legend_position(91.2, 22.2);
+
+    plot.set_bin_markers(&weekdays);
+    plot.set_bin_markers_middle();
+    plot.set_text_top("Share of traffic per device, regardless of how many visits each day had");
+    plot.set_text_top_offset(40.0);
+
+    plot.set_background_color("Black");
+    plot.set_plot_window_size(80.0, 35.0, 85.0, 50.0);
+    plot.set_show_horizontal_lines();
+    plot.set_bin_gap(20.0);
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn response_time_histogram() {
+    let output = Path::new("response_time_histogram.test.svg");
+
+    // Summing a few uniform samples gives a roughly bell shaped distribution.
+    let mut rng = rand::rng();
+    let samples: Vec<f64> = (0..2000).map(|_| {
+        (0..4).map(|_| rng.random_range(0.0..50.0)).sum::<f64>()
+    }).collect();
+
+    let histogram = Histogram::new(&samples, HistogramBins::FreedmanDiaconis);
+
+    let mut plot = BarPlot::new();
+    plot.add_histogram(&histogram);
+    plot.set_bar_colors_by_uniform("rgb(137, 174, 255)");
+    plot.set_bin_gap(0.0);
+
+    plot.set_text_top("Response times of 2000 requests, binned with the Freedman–Diaconis rule");
+    plot.set_text_top_offset(40.0);
+    plot.set_text_bottom("Response time (ms)");
+    plot.set_text_bottom_offset(25.0);
+    plot.set_text_left("Requests");
+    plot.set_text_left_offset(20.0);
+
+    plot.set_background_color("Black");
+    plot.set_plot_window_size(90.0, 70.0, 80.0, 40.0);
+    plot.set_scale_range(0, 300, 50);
+    plot.set_font_size(80.0);
+    plot.set_show_horizontal_lines();
+    plot.set_show_plot_border();
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn response_time_cumulative() {
+    let output = Path::new("response_time_cumulative.test.svg");
+
+    let mut rng = rand::rng();
+    let samples: Vec<f64> = (0..2000).map(|_| {
+        (0..4).map(|_| rng.random_range(0.0..50.0)).sum::<f64>()
+    }).collect();
+
+    let mut histogram = Histogram::new(&samples, HistogramBins::Width(10.0));
+    // Share of requests that took at least as long as the left edge of each bin.
+    histogram.set_cumulative_reversed();
+    histogram.set_fraction();
+
+    let mut plot = BarPlot::new();
+    plot.add_histogram(&histogram);
+    plot.set_scale_range_auto();
+    plot.set_bar_colors_by_uniform("rgb(255, 233, 133)");
+    plot.set_bin_gap(0.0);
+
+    plot.set_text_top("Fraction of requests that took at least X ms");
+    plot.set_text_top_offset(40.0);
+    plot.set_text_bottom("Response time (ms)");
+    plot.set_text_bottom_offset(25.0);
+
+    plot.set_background_color("Black");
+    plot.set_plot_window_size(90.0, 70.0, 80.0, 40.0);
+    plot.set_show_horizontal_lines();
+    plot.set_show_plot_border();
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn response_time_density() {
+    let output = Path::new("response_time_density.test.svg");
+
+    let mut rng = rand::rng();
+    // Two servers with very different amount of traffic.
+    let server_a: Vec<f64> = (0..5000).map(|_| {
+        (0..4).map(|_| rng.random_range(0.0..40.0)).sum::<f64>()
+    }).collect();
+    let server_b: Vec<f64> = (0..300).map(|_| {
+        (0..4).map(|_| rng.random_range(10.0..50.0)).sum::<f64>()
+    }).collect();
+
+    let edges: Vec<f64> = (0..=20).map(|i| i as f64 * 10.0).collect();
+
+    let mut histogram_a = Histogram::new(&server_a, HistogramBins::Edges(edges.clone()));
+    histogram_a.set_density();
+    histogram_a.set_kde("rgb(137, 174, 255)");
+
+    let mut histogram_b = Histogram::new(&server_b, HistogramBins::Edges(edges));
+    histogram_b.set_density();
+    histogram_b.set_kde("rgb(255, 233, 133)");
+
+    let mut plot = BarPlot::new();
+    plot.add_histogram(&histogram_a);
+    plot.add_bar_colors_by_category("rgb(67, 94, 155)");
+    plot.add_histogram(&histogram_b);
+    plot.add_bar_colors_by_category("rgb(155, 133, 33)");
+    plot.set_scale_range_auto();
+    plot.set_bin_gap(0.0);
+
+    let categories = ["Server A", "Server B"];
+    plot.set_legend(&categories);
+
+    plot.set_text_top("Response time density for two servers with 5000 and 300 requests");
+    plot.set_text_top_offset(40.0);
+    plot.set_text_bottom("Response time (ms)");
+    plot.set_text_bottom_offset(25.0);
+
+    plot.set_background_color("Black");
+    plot.set_plot_window_size(80.0, 40.0, 80.0, 40.0);
+    plot.set_show_horizontal_lines();
+    plot.set_show_plot_border();
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn benchmark_results() {
+    let output = Path::new("benchmark_results.test.svg");
+
+    let benchmarks = ["parse", "serialize", "compress", "hash", "sort"];
+    let mean_before = [42.1, 18.3, 77.4, 12.9, 35.0];
+    let std_dev_before = [3.2, 1.1, 9.8, 0.7, 4.4];
+    let mean_after = [31.7, 17.9, 54.2, 12.5, 22.3];
+    // 95% confidence interval, not symmetric around the mean.
+    let lower_after = [29.9, 16.2, 49.0, 11.8, 20.1];
+    let upper_after = [34.8, 19.0, 63.5, 13.4, 26.9];
+
+    let mut plot = BarPlot::new();
+
+    plot.add_values(&mean_before);
+    plot.add_error_bars(&std_dev_before);
+    plot.add_bar_colors_by_category("rgb(137, 174, 255)");
+
+    plot.add_values(&mean_after);
+    plot.add_error_bounds(&lower_after, &upper_after);
+    plot.add_bar_colors_by_category("rgb(126, 255, 165)");
+
+    plot.set_error_bar_color("White");
+    plot.set_error_bar_cap_width(40.0);
+    plot.set_error_bar_line_width(120.0);
+
+    let categories = ["Before", "After"];
+    plot.set_legend(&categories);
+    plot.set_legend_position(91.2, 22.2);
+
+    plot.set_bin_markers(&benchmarks);
+    plot.set_bin_markers_middle();
+    plot.set_scale_range(0, 100, 10);
+    plot.set_bar_gap(10.0);
+    plot.set_bin_gap(25.0);
+
+    plot.set_text_top("Mean run time with standard deviation (before) and 95% confidence interval (after)");
+    plot.set_text_top_offset(40.0);
+    plot.set_text_left("Run time (ms)");
+    plot.set_text_left_offset(25.0);
+
+    plot.set_background_color("Black");
+    plot.set_plot_window_size(80.0, 35.0, 85.0, 50.0);
+    plot.set_show_horizontal_lines();
+    plot.set_show_plot_border();
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn temperature_year_range() {
+    let output = Path::new("temperature_year_range.test.svg");
+    let months = [
+        "Jan", "Feb", "Mar", "Apr",
+        "May", "Jun", "Jul", "Aug",
+        "Sep", "Oct", "Nov", "Dec"
+    ];
+    let min_temperature = [
+        -19.8, -14.1, -7.7, -2.1,
+        3.4, 9.8, 12.6, 11.1,
+        4.2, -0.5, -6.3, -12.9
+    ];
+    let max_temperature = [
+        -2.4, 4.5, 9.0, 14.8,
+        21.7, 28.3, 31.5, 29.9,
+        22.1, 16.4, 10.2, 3.3
+    ];
+
+    let mut plot = BarPlot::new();
+    plot.set_bin_markers_middle();
+    plot.set_background_color("Black");
+    plot.set_show_horizontal_lines();
+    plot.add_ranges(&min_temperature, &max_temperature);
+    plot.set_bin_gap(30.0);
+
+    let min_color = "rgb(107, 235, 255)";
+    let low_color = "rgb(126, 255, 165)";
+    let high_color = "rgb(255, 233, 133)";
+    let max_color = "rgb(250, 107, 91)";
+    plot.set_bar_colors_by_threshold(min_color, low_color, high_color, max_color);
+
+    plot.set_bin_markers(&months);
+
+    plot.set_text_top("Monthly min and max temperature °C, colored by the max temperature");
+    plot.set_text_top_offset(40.0);
+
+    plot.set_plot_window_size(95.0, 80.0, 87.0, 55.0);
+    plot.set_scale_range(-20, 40, 10);
+    plot.set_y_axis_tick_length(0.0);
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn quarterly_revenue_waterfall() {
+    let output = Path::new("quarterly_revenue_waterfall.test.svg");
+    let labels = [
+        "Q1 sales", "Q2 sales", "Refunds", "H1 total",
+        "Q3 sales", "Q4 sales", "Refunds", "Year total"
+    ];
+    let changes = [
+        320.0, 280.0, -45.0, 0.0,
+        190.0, 410.0, -85.0, 0.0
+    ];
+    let totals = [3, 7];
+
+    let mut plot = BarPlot::new();
+    plot.set_background_color("Black");
+    plot.add_values(&changes);
+    plot.set_bars_waterfall();
+    plot.set_waterfall_totals(&totals);
+    plot.set_show_waterfall_connectors();
+    plot.set_line_color("LightGray");
+
+    plot.set_bar_colors_by_waterfall("rgb(126, 255, 165)", "rgb(250, 107, 91)", "rgb(107, 235, 255)");
+    plot.set_legend(&["Increase", "Decrease", "Total"]);
+    plot.set_legend_position(22.0, 18.0);
+
+    plot.set_bin_markers(&labels);
+    plot.set_bin_markers_middle();
+    plot.set_bin_gap(20.0);
+    plot.set_show_horizontal_lines();
+    plot.set_scale_range_auto();
+
+    plot.set_text_top("Revenue through the year (kNOK)");
+    plot.set_text_top_offset(40.0);
+    plot.set_plot_window_size(95.0, 80.0, 87.0, 55.0);
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn error_codes_pareto() {
+    let output = Path::new("error_codes_pareto.test.svg");
+    let error_codes = [
+        "E101", "E204", "E301", "E404", "E418",
+        "E500", "E502", "E503", "E504", "E999"
+    ];
+    let occurrences = [
+        31.0, 4.0, 12.0, 187.0, 2.0,
+        96.0, 18.0, 54.0, 9.0, 1.0
+    ];
+
+    let mut plot = BarPlot::new();
+    plot.set_background_color("Black");
+    plot.add_values(&occurrences);
+    plot.set_bin_markers(&error_codes);
+    plot.set_bin_markers_middle();
+    plot.set_bars_pareto();
+    plot.set_pareto_line_color("rgb(255, 233, 133)");
+    plot.set_bar_colors_by_uniform("rgb(107, 235, 255)");
+
+    plot.set_show_horizontal_lines();
+    plot.set_scale_range(0, 200, 20);
+    plot.set_bin_gap(20.0);
+
+    plot.set_text_top("Most frequent error codes last week");
+    plot.set_text_top_offset(40.0);
+    plot.set_text_left("Occurrences");
+    plot.set_text_left_offset(25.0);
+    plot.set_text_right("Cumulative share");
+    plot.set_text_right_offset(25.0);
+    plot.set_plot_window_size(85.0, 50.0, 85.0, 50.0);
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn sales_target_forecast() {
+    let output = Path::new("sales_target_forecast.test.svg");
+    let months = [
+        "Jan", "Feb", "Mar", "Apr",
+        "May", "Jun", "Jul", "Aug",
+        "Sep", "Oct", "Nov", "Dec"
+    ];
+    let actual = [
+        41.0, 38.5, 47.2, 52.9,
+        58.1, 64.0, 49.3, 45.8,
+        0.0, 0.0, 0.0, 0.0
+    ];
+    let target = [
+        40.0, 40.0, 45.0, 50.0,
+        55.0, 60.0, 55.0, 50.0,
+        55.0, 60.0, 65.0, 70.0
+    ];
+    let forecast = [
+        41.0, 38.5, 47.2, 52.9,
+        58.1, 64.0, 49.3, 45.8,
+        53.2, 57.9, 61.4, 68.8
+    ];
+
+    let mut plot = BarPlot::new();
+    plot.set_background_color("Black");
+    plot.add_values(&actual);
+    plot.set_bar_colors_by_uniform("rgb(107, 235, 255)");
+
+    plot.add_line_series(&target, "rgb(250, 107, 91)");
+    plot.set_line_series_width(70.0);
+    plot.set_line_series_dash(10.0, 6.0);
+
+    plot.add_line_series(&forecast, "rgb(255, 233, 133)");
+    plot.set_line_series_point_markers();
+
+    plot.set_bin_markers(&months);
+    plot.set_bin_markers_middle();
+    plot.set_show_horizontal_lines();
+    plot.set_scale_range_auto();
+
+    plot.set_text_top("Actual sales with target (dashed) and forecast");
+    plot.set_text_top_offset(40.0);
+    plot.set_plot_window_size(95.0, 80.0, 87.0, 55.0);
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn reaction_time_observations() {
+    let output = Path::new("reaction_time_observations.test.svg");
+    let groups = ["Placebo", "Low dose", "High dose"];
+    let mean_reaction_time = [312.0, 287.5, 254.2];
+
+    let bins = [
+        0, 0, 0, 0, 0, 0, 0, 0,
+        1, 1, 1, 1, 1, 1, 1, 1,
+        2, 2, 2, 2, 2, 2, 2, 2
+    ];
+    let observations = [
+        298.0, 305.5, 311.0, 312.5, 314.0, 318.5, 321.0, 315.5,
+        270.5, 281.0, 284.5, 288.0, 290.0, 292.5, 295.0, 298.5,
+        241.0, 246.5, 250.0, 252.5, 255.0, 258.5, 262.0, 268.0
+    ];
+    let outlier_bins = [0, 2];
+    let outliers = [371.0, 198.5];
+
+    let mut plot = BarPlot::new();
+    plot.set_background_color("Black");
+    plot.add_values(&mean_reaction_time);
+    plot.set_bar_colors_by_uniform("rgb(64, 96, 128)");
+
+    plot.add_points(&bins, &observations, "rgb(107, 235, 255)");
+    plot.set_points_jitter(40.0);
+
+    plot.add_points(&outlier_bins, &outliers, "rgb(250, 107, 91)");
+    plot.set_points_shape_cross();
+    plot.set_points_size(150.0);
+
+    plot.set_bin_markers(&groups);
+    plot.set_bin_markers_middle();
+    plot.set_bin_gap(30.0);
+    plot.set_show_horizontal_lines();
+    plot.set_scale_range_auto();
+
+    plot.set_text_top("Mean reaction time (ms) with every observation");
+    plot.set_text_top_offset(40.0);
+    plot.set_plot_window_size(95.0, 80.0, 87.0, 55.0);
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn daily_commits_lollipop() {
+    let output = Path::new("daily_commits_lollipop.test.svg");
+    let mut rng = rand::rng();
+    let commits: Vec<f64> = (0..120).map(|day| {
+        let weekday = day % 7 < 5;
+        let base = if weekday { 12.0 } else { 3.0 };
+        base + rng.random_range(0.0..8.0_f64).round()
+    }).collect();
+
+    let mut plot = BarPlot::new();
+    plot.set_background_color("Black");
+    plot.add_values(&commits);
+    plot.set_bar_style_lollipop();
+
+    let min_color = "rgb(107, 235, 255)";
+    let low_color = "rgb(126, 255, 165)";
+    let high_color = "rgb(255, 233, 133)";
+    let max_color = "rgb(250, 107, 91)";
+    plot.set_bar_colors_by_threshold(min_color, low_color, high_color, max_color);
+
+    plot.set_show_horizontal_lines();
+    plot.set_scale_range(0, 25, 5);
+    plot.set_x_axis_tick_length(0.0);
+
+    plot.set_text_top("Commits per day, last 120 days");
+    plot.set_text_top_offset(40.0);
+    plot.set_plot_window_size(95.0, 80.0, 87.0, 55.0);
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn life_expectancy_dumbbell() {
+    let output = Path::new("life_expectancy_dumbbell.test.svg");
+    let countries = ["Norway", "Sweden", "Denmark", "Finland", "Iceland"];
+    let year_1970 = [74.1, 74.7, 73.3, 70.2, 73.8];
+    let year_2020 = [83.2, 82.4, 81.6, 82.0, 83.1];
+
+    let mut plot = BarPlot::new();
+    plot.set_background_color("Black");
+    plot.add_values(&year_1970);
+    plot.add_bar_colors_by_category("rgb(107, 235, 255)");
+    plot.add_values(&year_2020);
+    plot.add_bar_colors_by_category("rgb(255, 233, 133)");
+    plot.set_bar_style_dumbbell();
+    plot.set_orientation_horizontal();
+    plot.set_line_color("LightGray");
+
+    plot.set_legend(&["1970", "2020"]);
+    plot.set_legend_position(88.0, 12.0);
+
+    plot.set_bin_markers(&countries);
+    plot.set_bin_markers_middle();
+    plot.set_show_vertical_lines();
+    plot.set_scale_range(65, 90, 5);
+
+    plot.set_text_top("Life expectancy at birth, 1970 and 2020");
+    plot.set_text_top_offset(40.0);
+    plot.set_plot_window_size(80.0, 70.0, 85.0, 50.0);
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn office_temperature_baseline() {
+    let output = Path::new("office_temperature_baseline.test.svg");
+    let hours = [
+        "07", "08", "09", "10", "11", "12",
+        "13", "14", "15", "16", "17", "18"
+    ];
+    let temperature = [
+        17.2, 18.1, 19.4, 20.6, 21.9, 23.1,
+        23.8, 23.4, 22.5, 21.0, 19.7, 18.6
+    ];
+
+    let mut plot = BarPlot::new();
+    plot.set_background_color("Black");
+    plot.add_values(&temperature);
+    plot.set_baseline(20.0);
+    plot.set_bar_colors_by_baseline("rgb(250, 107, 91)", "rgb(107, 235, 255)");
+    plot.set_line_color("LightGray");
+
+    plot.set_legend(&["Warmer than 20°C", "Colder than 20°C"]);
+    plot.set_legend_position(80.0, 18.0);
+
+    plot.set_bin_markers(&hours);
+    plot.set_bin_markers_middle();
+    plot.set_bin_gap(25.0);
+    plot.set_scale_range(15, 26, 1);
+
+    plot.set_text_top("Office temperature through the day");
+    plot.set_text_top_offset(40.0);
+    plot.set_plot_window_size(95.0, 80.0, 87.0, 55.0);
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn population_pyramid() {
+    let output = Path::new("population_pyramid.test.svg");
+    let age_groups = [
+        "90+", "80-89", "70-79", "60-69", "50-59",
+        "40-49", "30-39", "20-29", "10-19", "0-9"
+    ];
+    let men = [
+        0.3, 1.6, 4.1, 5.9, 6.8,
+        6.7, 7.1, 6.9, 6.2, 5.6
+    ];
+    let women = [
+        0.8, 2.5, 4.6, 6.0, 6.6,
+        6.4, 6.9, 6.6, 5.9, 5.3
+    ];
+
+    let mut plot = BarPlot::new();
+    plot.set_background_color("Black");
+    plot.add_values(&men);
+    plot.add_bar_colors_by_category("rgb(107, 235, 255)");
+    plot.add_values(&women);
+    plot.add_bar_colors_by_category("rgb(250, 107, 91)");
+    plot.set_legend(&["Men", "Women"]);
+    plot.set_legend_position(88.0, 12.0);
+
+    plot.set_bars_pyramid();
+    plot.set_bin_markers(&age_groups);
+    plot.set_pyramid_markers_middle(10.0);
+    plot.set_bin_gap(15.0);
+    plot.set_show_vertical_lines();
+    plot.set_scale_range(0, 8, 1);
+
+    plot.set_text_top("Population by age group (% of total)");
+    plot.set_text_top_offset(40.0);
+    plot.set_plot_window_size(90.0, 50.0, 80.0, 55.0);
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn income_brackets() {
+    let output = Path::new("income_brackets.test.svg");
+    // Share of households per 100 000 of yearly income, so that the area of each bar is the share of households.
+    let brackets = [0.0, 150_000.0, 300_000.0, 500_000.0, 750_000.0, 1_000_000.0, 1_500_000.0, 2_500_000.0];
+    let households = [7.2, 11.4, 17.8, 19.6, 16.3, 18.1, 9.6];
+    let share_per_100k: Vec<f64> = households.iter().zip(brackets.windows(2)).map(|(share, edges)| {
+        share / ((edges[1] - edges[0]) / 100_000.0)
+    }).collect();
+    let markers = ["0", "150k", "300k", "500k", "750k", "1M", "1.5M", "2.5M"];
+
+    let mut plot = BarPlot::new();
+    plot.set_background_color("Black");
+    plot.add_values(&share_per_100k);
+    plot.set_bin_edges(&brackets);
+    plot.set_bin_markers(&markers);
+    plot.set_bin_markers_left();
+    plot.set_bin_gap(2.0);
+    plot.set_bar_colors_by_uniform("rgb(107, 235, 255)");
+
+    plot.set_show_horizontal_lines();
+    plot.set_scale_range_auto();
+
+    plot.set_text_top("Households by yearly income (% per 100k NOK)");
+    plot.set_text_top_offset(40.0);
+    plot.set_plot_window_size(95.0, 80.0, 87.0, 55.0);
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn market_share_marimekko() {
+    let output = Path::new("market_share_marimekko.test.svg");
+    let market_size = [27.0, 84.0, 68.0, 41.0];
+    let leader = [34.0, 21.0, 17.0, 26.0];
+    let runner_up = [22.0, 19.0, 15.0, 24.0];
+    let others = [44.0, 60.0, 68.0, 50.0];
+
+    let mut plot = BarPlot::new();
+    plot.set_background_color("Black");
+    plot.add_values(&leader);
+    plot.add_bar_colors_by_category("rgb(107, 235, 255)");
+    plot.add_values(&runner_up);
+    plot.add_bar_colors_by_category("rgb(255, 233, 133)");
+    plot.add_values(&others);
+    plot.add_bar_colors_by_category("rgb(110, 110, 110)");
+    plot.set_legend(&["Leader", "Runner up", "Others"]);
+    plot.set_legend_position(90.0, 20.0);
+
+    plot.set_bars_stacked_percent();
+    plot.set_bin_widths(&market_size);
+    plot.set_bin_gap(1.0);
+
+    plot.set_text_top("Market share, bin width by market size (bn NOK)");
+    plot.set_text_top_offset(40.0);
+    plot.set_plot_window_size(80.0, 50.0, 85.0, 50.0);
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn hourly_traffic_radial() {
+    let output = Path::new("hourly_traffic_radial.test.svg");
+    let hours: Vec<String> = (0..24).map(|hour| format!("{hour:02}")).collect();
+    let hours: Vec<&str> = hours.iter().map(|s| s.as_str()).collect();
+    let cars = [
+        120.0, 80.0, 60.0, 55.0, 90.0, 310.0,
+        820.0, 1240.0, 1010.0, 640.0, 560.0, 590.0,
+        610.0, 580.0, 620.0, 790.0, 1180.0, 1320.0,
+        960.0, 610.0, 430.0, 330.0, 250.0, 170.0
+    ];
+
+    let mut plot = BarPlot::new();
+    plot.set_background_color("Black");
+    plot.add_values(&cars);
+    plot.set_orientation_radial();
+
+    let min_color = "rgb(107, 235, 255)";
+    let low_color = "rgb(126, 255, 165)";
+    let high_color = "rgb(255, 233, 133)";
+    let max_color = "rgb(250, 107, 91)";
+    plot.set_bar_colors_by_threshold(min_color, low_color, high_color, max_color);
+
+    plot.set_bin_markers(&hours);
+    plot.set_bin_markers_left();
+    plot.set_bin_gap(8.0);
+    plot.set_show_horizontal_lines();
+    plot.set_show_vertical_lines();
+    plot.set_scale_range(0, 1400, 200);
+
+    plot.set_text_top("Cars passing per hour");
+    plot.set_text_top_offset(40.0);
+    plot.set_plot_window_size(80.0, 50.0, 80.0, 55.0);
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn wind_rose_month() {
+    let output = Path::new("wind_rose_month.test.svg");
+    let mut rng = rand::rng();
+    // Hourly samples for a month, mostly from south-west with the occasional northerly.
+    let samples: Vec<(f64, f64)> = (0..720).map(|_| {
+        let direction: f64 = if rng.random_bool(0.8) {
+            rng.random_range(170.0..280.0)
+        } else {
+            rng.random_range(-30.0..40.0)
+        };
+        let speed: f64 = rng.random_range(0.0..14.0);
+        (direction, speed)
+    }).collect();
+
+    let wind_rose = WindRose::new(&samples, 16, &[0.5, 3.0, 6.0, 9.0, 12.0]);
+
+    let mut plot = BarPlot::new();
+    plot.set_background_color("Black");
+    plot.add_wind_rose(&wind_rose);
+    for color in [
+        "rgb(107, 235, 255)",
+        "rgb(126, 255, 165)",
+        "rgb(255, 233, 133)",
+        "rgb(255, 170, 90)",
+        "rgb(250, 107, 91)",
+    ] {
+        plot.add_bar_colors_by_category(color);
+    }
+    plot.set_legend_position(88.0, 20.0);
+
+    plot.set_show_horizontal_lines();
+    plot.set_show_vertical_lines();
+    plot.set_scale_range_auto();
+
+    plot.set_text_top("Wind by direction and speed (m/s), % of hours");
+    plot.set_text_top_offset(40.0);
+    plot.set_plot_window_size(80.0, 50.0, 78.0, 55.0);
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn regional_sales_figure() {
+    let output = Path::new("regional_sales_figure.test.svg");
+    let quarters = ["Q1", "Q2", "Q3", "Q4"];
+    let regions = ["North", "South", "East", "West", "Central", "Coast"];
+    let sales = [
+        ([42.0, 55.0, 61.0, 48.0], [38.0, 41.0, 52.0, 57.0]),
+        ([21.0, 26.0, 30.0, 35.0], [25.0, 24.0, 28.0, 31.0]),
+        ([64.0, 58.0, 70.0, 77.0], [59.0, 63.0, 66.0, 71.0]),
+        ([33.0, 29.0, 36.0, 40.0], [30.0, 35.0, 34.0, 39.0]),
+        ([12.0, 18.0, 15.0, 22.0], [14.0, 16.0, 19.0, 20.0]),
+        ([47.0, 52.0, 49.0, 58.0], [44.0, 50.0, 55.0, 53.0]),
+    ];
+
+    let mut figure = Figure::new(2, 3);
+    figure.set_background_color("Black");
+
+    for (region, (last_year, this_year)) in regions.iter().zip(sales.iter()) {
+        let mut plot = BarPlot::new();
+        plot.add_values(last_year);
+        plot.add_values(this_year);
+        plot.add_bar_colors_by_category("rgb(107, 235, 255)");
+        plot.add_bar_colors_by_category("rgb(255, 233, 133)");
+        plot.set_show_horizontal_lines();
+        plot.set_bar_gap(5.0);
+        plot.set_text_top(region);
+        plot.set_text_top_offset(40.0);
+        plot.set_plot_window_size(85.0, 70.0, 70.0, 55.0);
+        figure.add_plot(plot);
+    }
+
+    figure.set_shared_scale();
+    figure.set_shared_bin_markers(&quarters);
+    figure.set_legend(&["Last year", "This year"]);
+    figure.set_legend_position(92.0, 4.0);
+
+    let contents = figure.to_svg(1800, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn revenue_by_product_stack_groups() {
+    let output = Path::new("revenue_by_product_stack_groups.test.svg");
+    let months = ["Jan", "Feb", "Mar", "Apr", "May", "Jun"];
+    let hardware_2024 = [12.0, 14.0, 13.0, 16.0, 18.0, 17.0];
+    let software_2024 = [8.0, 9.0, 11.0, 10.0, 12.0, 14.0];
+    let services_2024 = [4.0, 4.0, 5.0, 6.0, 5.0, 7.0];
+    let hardware_2025 = [13.0, 15.0, 16.0, 15.0, 19.0, 21.0];
+    let software_2025 = [10.0, 12.0, 12.0, 14.0, 15.0, 16.0];
+    let services_2025 = [5.0, 6.0, 6.0, 7.0, 8.0, 8.0];
+
+    let mut plot = BarPlot::new();
+    plot.set_background_color("Black");
+
+    for (group, values) in [
+        (0, [&hardware_2024, &software_2024, &services_2024]),
+        (1, [&hardware_2025, &software_2025, &services_2025]),
+    ] {
+        for values in values {
+            plot.add_values(values);
+            plot.set_stack_group(group);
+        }
+    }
+    for color in [
+        "rgb(107, 235, 255)",
+        "rgb(126, 255, 165)",
+        "rgb(255, 233, 133)",
+        "rgb(60, 150, 170)",
+        "rgb(70, 160, 100)",
+        "rgb(170, 150, 80)",
+    ] {
+        plot.add_bar_colors_by_category(color);
+    }
+    let categories = [
+        "Hardware 2024", "Software 2024", "Services 2024",
+        "Hardware 2025", "Software 2025", "Services 2025",
+    ];
+    plot.set_legend(&categories);
+    plot.set_legend_position(86.0, 20.0);
+
+    plot.set_bars_stacked();
+    plot.set_bar_gap(8.0);
+    plot.set_bin_gap(20.0);
+    plot.set_show_stack_totals();
+    plot.set_scale_range(0, 50, 5);
+    plot.set_show_horizontal_lines();
+    plot.set_bin_markers(&months);
+
+    plot.set_text_top("Revenue by product (million), 2024 and 2025 side by side");
+    plot.set_text_top_offset(40.0);
+    plot.set_plot_window_size(70.0, 40.0, 80.0, 50.0);
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn kpi_bullet_chart() {
+    let output = Path::new("kpi_bullet_chart.test.svg");
+    let kpis = ["Revenue", "Profit", "Avg. order", "New customers", "Satisfaction"];
+    // Everything as a percentage of last year's value.
+    let actual = [108.0, 87.0, 96.0, 121.0, 99.0];
+    let targets = [110.0, 100.0, 100.0, 115.0, 105.0];
+    let poor = [80.0, 70.0, 85.0, 80.0, 90.0];
+    let fair = [100.0, 95.0, 100.0, 105.0, 100.0];
+    let good = [130.0, 130.0, 130.0, 130.0, 130.0];
+
+    let mut plot = BarPlot::new();
+    plot.set_background_color("Black");
+    plot.add_values(&actual);
+    plot.set_bar_colors_by_uniform("rgb(107, 235, 255)");
+
+    plot.set_bars_bullet();
+    plot.add_bullet_range(&poor, "rgb(60, 60, 60)");
+    plot.add_bullet_range(&fair, "rgb(95, 95, 95)");
+    plot.add_bullet_range(&good, "rgb(130, 130, 130)");
+    plot.set_bullet_targets(&targets);
+    plot.set_bullet_target_color("White");
+
+    plot.set_orientation_horizontal();
+    plot.set_bin_markers(&kpis);
+    plot.set_bin_gap(30.0);
+    plot.set_scale_range(0, 130, 10);
+
+    plot.set_text_top("Key figures, % of last year");
+    plot.set_text_top_offset(40.0);
+    plot.set_plot_window_size(75.0, 80.0, 80.0, 50.0);
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn stock_sparkline() {
+    let output = Path::new("stock_sparkline.test.svg");
+    let daily_change = [
+        0.4, -1.2, 0.8, 1.9, -0.3, -2.1, 0.6, 1.1, 0.2, -0.7,
+        1.4, 2.3, -0.9, 0.5, -1.6, 0.9, 1.2, -0.4, 0.3, 1.7,
+    ];
+
+    let mut plot = BarPlot::new();
+    plot.set_background_color("Black");
+    plot.add_values(&daily_change);
+    plot.set_bar_colors_by_baseline("rgb(126, 255, 165)", "rgb(250, 107, 91)");
+    plot.set_baseline(0.0);
+    plot.set_bin_gap(25.0);
+    plot.set_sparkline_last_bar_color("rgb(255, 233, 133)");
+    plot.set_sparkline_min_max_color("rgb(107, 235, 255)");
+
+    let contents = plot.to_sparkline_svg(120, 24);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn stock_sparkline_flat() {
+    let output = Path::new("stock_sparkline_flat.test.svg");
+    // Market closed all week, so there is no change at all.
+    let daily_change = [0.0; 5];
+
+    let mut plot = BarPlot::new();
+    plot.set_background_color("Black");
+    plot.add_values(&daily_change);
+    plot.set_sparkline_last_bar_color("rgb(255, 233, 133)");
+    plot.set_sparkline_min_max_color("rgb(107, 235, 255)");
+
+    let contents = plot.to_sparkline_svg(120, 24);
+    assert!(!contents.contains("NaN") && !contents.contains("inf"), "A flat sparkline must have finite coordinates.");
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn stock_price_candlesticks() {
+    let output = Path::new("stock_price_candlesticks.test.svg");
+    let days = ["Mon", "Tue", "Wed", "Thu", "Fri", "Mon", "Tue", "Wed", "Thu", "Fri"];
+    let open = [101.2, 103.8, 102.9, 99.4, 102.1, 104.0, 106.3, 105.2, 107.8, 108.4];
+    let high = [104.5, 105.1, 103.3, 102.8, 104.6, 107.2, 106.9, 108.3, 109.5, 108.9];
+    let low = [100.3, 102.2, 98.7, 98.9, 101.5, 103.1, 104.4, 104.8, 106.9, 104.2];
+    let close = [103.8, 102.9, 99.4, 102.1, 104.0, 106.3, 105.2, 107.8, 108.4, 105.1];
+
+    let mut plot = BarPlot::new();
+    plot.set_background_color("Black");
+    plot.add_candlesticks(&open, &high, &low, &close);
+    plot.set_bar_colors_by_candlestick("rgb(126, 255, 165)", "rgb(250, 107, 91)");
+
+    plot.set_bin_markers(&days);
+    plot.set_bin_gap(40.0);
+    plot.set_scale_range(96, 112, 2);
+    plot.set_show_horizontal_lines();
+
+    plot.set_text_top("Share price, last two weeks");
+    plot.set_text_top_offset(40.0);
+    plot.set_plot_window_size(90.0, 70.0, 80.0, 50.0);
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn page_load_box_plots() {
+    let output = Path::new("page_load_box_plots.test.svg");
+    let pages = ["Home", "Search", "Product", "Cart", "Checkout"];
+    let typical = [320.0, 540.0, 410.0, 380.0, 620.0];
+    let mut rng = rand::rng();
+
+    // Page load times (ms) before and after a release, with the occasional slow request.
+    let mut sample = |typical: f64, n: usize| -> Vec<f64> {
+        (0..n).map(|_| {
+            let slow = if rng.random_bool(0.05) { rng.random_range(2.0..4.0) } else { 1.0 };
+            typical * rng.random_range(0.7..1.3) * slow
+        }).collect()
+    };
+    let before: Vec<Vec<f64>> = typical.iter().map(|t| sample(*t, 200)).collect();
+    let after: Vec<Vec<f64>> = typical.iter().map(|t| sample(*t * 0.8, 200)).collect();
+    let before: Vec<&[f64]> = before.iter().map(|v| v.as_slice()).collect();
+    let after: Vec<&[f64]> = after.iter().map(|v| v.as_slice()).collect();
+
+    let before = BoxPlot::new(&before, BoxWhiskers::Iqr(1.5));
+    let after = BoxPlot::new(&after, BoxWhiskers::Iqr(1.5));
+
+    let mut plot = BarPlot::new();
+    plot.set_background_color("Black");
+    plot.add_box_plot(&before);
+    plot.add_bar_colors_by_category("rgb(107, 235, 255)");
+    plot.add_box_plot(&after);
+    plot.add_bar_colors_by_category("rgb(255, 233, 133)");
+    plot.set_line_color("White");
+    plot.set_legend(&["Before", "After"]);
+
+    plot.set_bin_markers(&pages);
+    plot.set_bin_gap(30.0);
+    plot.set_bar_gap(15.0);
+    plot.set_scale_range_auto();
+    plot.set_show_horizontal_lines();
+
+    plot.set_text_top("Page load time (ms) before and after release");
+    plot.set_text_top_offset(40.0);
+    plot.set_plot_window_size(85.0, 70.0, 80.0, 50.0);
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn batch_job_timeline() {
+    let output = Path::new("batch_job_timeline.test.svg");
+    let jobs = ["Backup", "Reindex", "Export", "Report", "Cleanup", "Sync"];
+    let at = |hour: u32, minute: u32| -> f64 {
+        NaiveDate::from_ymd_opt(2025, 3, 1).unwrap().and_hms_opt(hour, minute, 0).unwrap().and_utc().timestamp() as f64
+    };
+
+    // Night run for each job, and a second run during the day for some of them.
+    let night_start = [at(0, 30), at(1, 15), at(2, 0), at(4, 45), at(5, 0), at(0, 0)];
+    let night_end = [at(2, 10), at(3, 40), at(4, 30), at(5, 30), at(5, 20), at(0, 50)];
+    let day_start = [f64::NAN, at(13, 0), f64::NAN, at(12, 0), f64::NAN, at(12, 0)];
+    let day_end = [f64::NAN, at(15, 20), f64::NAN, at(12, 40), f64::NAN, at(12, 50)];
+
+    let mut plot = BarPlot::new();
+    plot.set_background_color("Black");
+    plot.add_timeline(&night_start, &night_end);
+    plot.add_bar_colors_by_category("rgb(107, 235, 255)");
+    plot.add_timeline(&day_start, &day_end);
+    plot.add_bar_colors_by_category("rgb(255, 233, 133)");
+    // The export failed.
+    plot.add_bar_color_override(0, 2, "rgb(250, 107, 91)");
+    plot.set_legend(&["Night run", "Day run"]);
+    plot.set_legend_position(88.0, 12.0);
+
+    plot.set_bin_markers(&jobs);
+    plot.set_bin_gap(30.0);
+    plot.set_show_vertical_lines();
+    plot.add_scale_line_at(at(9, 25), "rgb(250, 107, 91)");
+
+    plot.set_text_top("Batch jobs, 2025-03-01 (UTC)");
+    plot.set_text_top_offset(40.0);
+    plot.set_plot_window_size(80.0, 80.0, 80.0, 50.0);
+
+    let contents = plot.to_svg(1600, 1000);
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Error saving '{}', {}", output.display(), e);
+    }
+}
+
+#[test]
+fn batch_job_timeline_empty() {
+    let output = Path::new("batch_job_timeline_empty.test.svg");
+    let jobs = ["Backup", "Reindex", "Export"];
+    // No jobs ran today, so every row is left empty.
+    let no_runs = [f64::NAN; 3];
+
+    let mut plot = BarPlot::new();
+    plot.set_background_color("Black");
+    plot.add_timeline(&no_runs, &no_runs);
+    plot.set_bin_markers(&jobs);
+    plot.set_plot_window_size(80.0, 80.0, 80.0, 50.0);
+
+    let contents = plot.to_svg(1600, 1000);
+    assert!(!contents.contains("NaN") && !contents.contains("inf"), "An empty timeline must have finite coordinates.");
+    if let Err(e) = fs::write(output, contents) {
         eprintln!("Error saving '{}', {}", output.display(), e);
     }
 }